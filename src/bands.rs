@@ -0,0 +1,132 @@
+use crate::iso::*;
+use crate::*;
+pub use Spacing::*;
+
+pub const BAND_OPTIONS: [usize; 3] = [7, 10, 31];
+
+pub const SPACING_OPTIONS: [Spacing; 3] = [
+    Spacing::Octave,
+    Spacing::TwoThirdOctave,
+    Spacing::ThirdOctave,
+];
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Spacing {
+    Octave,
+    TwoThirdOctave,
+    ThirdOctave,
+}
+
+impl Spacing {
+    pub fn as_str(&self) -> String {
+        match self {
+            Octave => "octave".into(),
+            TwoThirdOctave => "2/3 octave".into(),
+            ThirdOctave => "1/3 octave".into(),
+        }
+    }
+
+    pub fn octaves(&self) -> f64 {
+        match self {
+            Octave => 1.0,
+            TwoThirdOctave => 2.0 / 3.0,
+            ThirdOctave => 1.0 / 3.0,
+        }
+    }
+}
+
+impl From<String> for Spacing {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "octave" => Octave,
+            "2/3 octave" => TwoThirdOctave,
+            "1/3 octave" => ThirdOctave,
+            _ => Octave,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Band(f64, Option<Selection>);
+
+impl Band {
+    pub fn new(frequency: f64, selection: Option<Selection>) -> Band {
+        Band(frequency, selection)
+    }
+
+    pub fn frequency(&self) -> f64 {
+        self.0
+    }
+
+    pub fn selection(&self) -> Option<&Selection> {
+        self.1.as_ref()
+    }
+}
+
+pub fn band_frequencies(count: usize, spacing: Spacing, lowest: f64) -> Vec<f64> {
+    (0..count)
+        .map(|band| lowest * 2f64.powf(band as f64 * spacing.octaves()))
+        .collect()
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn band_candidates(
+    count: usize,
+    spacing: Spacing,
+    preset: Preset,
+    frequency: Arg,
+    q_factor: Arg,
+    r1: Arg,
+    r2: Arg,
+    c1: Arg,
+    c2: Arg,
+) -> Result<Vec<(f64, f64, Vec<Selection>)>, CalcError> {
+    frequency.validate("frequency")?;
+
+    let (lowest, _, _) = value_to_tolerance(frequency.clone());
+
+    preset_frequencies(preset, count, spacing, lowest)
+        .into_iter()
+        .map(|band| {
+            let selections = calculate(
+                frequency.retarget(band),
+                q_factor.clone(),
+                r1.clone(),
+                r2.clone(),
+                c1.clone(),
+                c2.clone(),
+            )?;
+
+            let (q_factor_target, _, _) = value_to_tolerance(q_factor.to_q_factor(band));
+
+            Ok((band, q_factor_target, selections))
+        })
+        .collect()
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn calculate_bands(
+    count: usize,
+    spacing: Spacing,
+    preset: Preset,
+    frequency: Arg,
+    q_factor: Arg,
+    r1: Arg,
+    r2: Arg,
+    c1: Arg,
+    c2: Arg,
+) -> Result<Vec<Band>, CalcError> {
+    let bands = band_candidates(count, spacing, preset, frequency, q_factor, r1, r2, c1, c2)?
+        .into_iter()
+        .map(|(band, q_factor_target, selections)| {
+            let selection = selections.into_iter().min_by(|a, b| {
+                a.error(band, q_factor_target)
+                    .total_cmp(&b.error(band, q_factor_target))
+            });
+
+            Band(band, selection)
+        })
+        .collect();
+
+    Ok(bands)
+}
@@ -0,0 +1,119 @@
+use serde::{Deserialize, Serialize};
+pub use QMeasure::*;
+
+pub const MEASURE_OPTIONS: [QMeasure; 4] = [QFactor, BandwidthHz, BandwidthOctaves, DampingRatio];
+
+#[derive(Clone, Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub enum QMeasure {
+    QFactor,
+    BandwidthHz,
+    BandwidthOctaves,
+    DampingRatio,
+}
+
+impl QMeasure {
+    pub fn as_str(&self) -> String {
+        match self {
+            QFactor => "q".into(),
+            BandwidthHz => "hz".into(),
+            BandwidthOctaves => "octaves".into(),
+            DampingRatio => "damping".into(),
+        }
+    }
+
+    pub fn to_q_factor(&self, value: f64, frequency: f64) -> f64 {
+        match self {
+            QFactor => value,
+            BandwidthHz => q_from_bandwidth(frequency, value),
+            BandwidthOctaves => q_from_octaves(value),
+            DampingRatio => q_from_damping(value),
+        }
+    }
+
+    pub fn from_q_factor(&self, q_factor: f64, frequency: f64) -> f64 {
+        match self {
+            QFactor => q_factor,
+            BandwidthHz => bandwidth_from_q(frequency, q_factor),
+            BandwidthOctaves => octaves_from_q(q_factor),
+            DampingRatio => damping_from_q(q_factor),
+        }
+    }
+}
+
+impl From<String> for QMeasure {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "q" => QFactor,
+            "hz" => BandwidthHz,
+            "octaves" => BandwidthOctaves,
+            "damping" => DampingRatio,
+            _ => QFactor,
+        }
+    }
+}
+
+pub fn q_from_bandwidth(frequency: f64, bandwidth: f64) -> f64 {
+    frequency / bandwidth
+}
+
+pub fn bandwidth_from_q(frequency: f64, q_factor: f64) -> f64 {
+    frequency / q_factor
+}
+
+pub fn q_from_octaves(octaves: f64) -> f64 {
+    let ratio = 2f64.powf(octaves);
+
+    ratio.sqrt() / (ratio - 1.0)
+}
+
+pub fn octaves_from_q(q_factor: f64) -> f64 {
+    2.0 / 2f64.ln() * (1.0 / (2.0 * q_factor)).asinh()
+}
+
+pub fn q_from_damping(damping: f64) -> f64 {
+    1.0 / (2.0 * damping)
+}
+
+pub fn damping_from_q(q_factor: f64) -> f64 {
+    1.0 / (2.0 * q_factor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::SQRT_2;
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-4
+    }
+
+    #[test]
+    fn octave_bandwidths() {
+        assert!(close(q_from_octaves(1.0), SQRT_2));
+        assert!(close(q_from_octaves(2.0 / 3.0), 2.1449));
+        assert!(close(q_from_octaves(1.0 / 3.0), 4.3185));
+        assert!(close(octaves_from_q(SQRT_2), 1.0));
+        assert!(close(octaves_from_q(4.318473), 1.0 / 3.0));
+    }
+
+    #[test]
+    fn hertz_and_damping() {
+        assert!(close(q_from_bandwidth(1000.0, 250.0), 4.0));
+        assert!(close(bandwidth_from_q(1000.0, 4.0), 250.0));
+        assert!(close(q_from_damping(0.5), 1.0));
+        assert!(close(damping_from_q(1.0 / SQRT_2), 1.0 / SQRT_2));
+    }
+
+    #[test]
+    fn measures_round_trip() {
+        for measure in MEASURE_OPTIONS {
+            let value = measure.from_q_factor(3.0, 1000.0);
+
+            assert!(
+                close(measure.to_q_factor(value, 1000.0), 3.0),
+                "{:?}",
+                measure
+            );
+        }
+    }
+}
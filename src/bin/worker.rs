@@ -0,0 +1,6 @@
+use gloo_worker::Registrable;
+use gyrator_calculator::worker::SearchWorker;
+
+fn main() {
+    SearchWorker::registrar().register();
+}
@@ -0,0 +1,482 @@
+use crate::bands::*;
+use crate::iso::*;
+use crate::*;
+pub use Optimize::*;
+pub use Part::*;
+
+pub const OPTIMIZE_OPTIONS: [Optimize; 3] = [Closest, FewestParts, SharedResistors];
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Optimize {
+    Closest,
+    FewestParts,
+    SharedResistors,
+}
+
+impl Optimize {
+    pub fn as_str(&self) -> String {
+        match self {
+            Closest => "closest match".into(),
+            FewestParts => "fewest parts".into(),
+            SharedResistors => "shared r1/r2".into(),
+        }
+    }
+}
+
+impl From<String> for Optimize {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "closest match" => Closest,
+            "fewest parts" => FewestParts,
+            "shared r1/r2" => SharedResistors,
+            _ => Closest,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Part {
+    Resistor(f64),
+    Capacitor(f64),
+    Inductor(f64),
+}
+
+impl Part {
+    pub fn as_str(&self) -> String {
+        match self {
+            Resistor(_) => "resistor".into(),
+            Capacitor(_) => "capacitor".into(),
+            Inductor(_) => "inductor".into(),
+        }
+    }
+
+    pub fn value(&self) -> f64 {
+        match self {
+            Resistor(value) => *value,
+            Capacitor(value) => *value,
+            Inductor(value) => *value,
+        }
+    }
+
+    fn rank(&self) -> usize {
+        match self {
+            Resistor(_) => 0,
+            Capacitor(_) => 1,
+            Inductor(_) => 2,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct BomLine(Part, usize);
+
+impl BomLine {
+    pub fn part(&self) -> &Part {
+        &self.0
+    }
+
+    pub fn quantity(&self) -> usize {
+        self.1
+    }
+}
+
+fn parts(selection: &Selection) -> [Part; 4] {
+    [
+        Resistor(selection.r1_resistance()),
+        Resistor(selection.r2_resistance()),
+        Capacitor(selection.c1_capacitance()),
+        Capacitor(selection.c2_capacitance()),
+    ]
+}
+
+fn tally(lines: &mut Vec<BomLine>, selection: &Selection) {
+    for part in parts(selection) {
+        if let Some(line) = lines.iter_mut().find(|line| line.0 == part) {
+            line.1 += 1;
+        } else {
+            lines.push(BomLine(part, 1));
+        }
+    }
+}
+
+fn untally(lines: &mut Vec<BomLine>, selection: &Selection) {
+    for part in parts(selection) {
+        if let Some(index) = lines.iter().position(|line| line.0 == part) {
+            if lines[index].1 > 1 {
+                lines[index].1 -= 1;
+            } else {
+                lines.remove(index);
+            }
+        }
+    }
+}
+
+fn line_change(lines: &[BomLine], current: Option<&Selection>, selection: &Selection) -> isize {
+    let mut changes: Vec<(Part, isize)> = Vec::with_capacity(8);
+    let removed = current
+        .map(parts)
+        .into_iter()
+        .flatten()
+        .map(|part| (part, -1));
+    let added = parts(selection).into_iter().map(|part| (part, 1));
+
+    for (part, change) in removed.chain(added) {
+        match changes.iter_mut().find(|(other, _)| *other == part) {
+            Some((_, total)) => *total += change,
+            None => changes.push((part, change)),
+        }
+    }
+
+    changes
+        .iter()
+        .map(|(part, change)| {
+            let quantity = lines
+                .iter()
+                .find(|line| line.0 == *part)
+                .map_or(0, |line| line.1 as isize);
+
+            match (quantity > 0, quantity + change > 0) {
+                (false, true) => 1,
+                (true, false) => -1,
+                _ => 0,
+            }
+        })
+        .sum()
+}
+
+fn compare(a: &Part, b: &Part) -> std::cmp::Ordering {
+    a.rank()
+        .cmp(&b.rank())
+        .then_with(|| a.value().total_cmp(&b.value()))
+}
+
+pub fn bill_of_materials(bands: &[Band]) -> Vec<BomLine> {
+    let mut lines = Vec::new();
+
+    for selection in bands.iter().filter_map(|band| band.selection()) {
+        tally(&mut lines, selection);
+    }
+
+    lines.sort_by(|a, b| compare(&a.0, &b.0));
+
+    lines
+}
+
+type Choice<'a> = Vec<Option<&'a Selection>>;
+
+fn cost(candidates: &[(f64, f64, Vec<Selection>)], chosen: &Choice) -> (usize, usize, f64) {
+    let mut lines = Vec::new();
+    let mut missing = 0;
+    let mut error = 0.0;
+
+    for ((band, q_factor, _), selection) in candidates.iter().zip(chosen) {
+        if let Some(selection) = selection {
+            tally(&mut lines, selection);
+            error += selection.error(*band, *q_factor);
+        } else {
+            missing += 1;
+        }
+    }
+
+    (missing, lines.len(), error)
+}
+
+fn is_cheaper(a: (usize, usize, f64), b: (usize, usize, f64)) -> bool {
+    (a.0, a.1) < (b.0, b.1) || ((a.0, a.1) == (b.0, b.1) && a.2 + f64::EPSILON.sqrt() < b.2)
+}
+
+fn closest(candidates: &[(f64, f64, Vec<Selection>)]) -> Choice<'_> {
+    candidates
+        .iter()
+        .map(|(band, q_factor, selections)| {
+            selections.iter().min_by(|a, b| {
+                a.error(*band, *q_factor)
+                    .total_cmp(&b.error(*band, *q_factor))
+            })
+        })
+        .collect()
+}
+
+fn pinned(arg: &Arg) -> Option<f64> {
+    match arg {
+        ArgWithExact(value) => Some(*value),
+        _ => None,
+    }
+}
+
+fn shared<'a>(
+    candidates: &'a [(f64, f64, Vec<Selection>)],
+    (r1, r2): (Option<f64>, Option<f64>),
+) -> Choice<'a> {
+    let mut pairs: Vec<(f64, f64)> = Vec::new();
+
+    for selection in candidates.iter().flat_map(|(_, _, selections)| selections) {
+        let pair = (selection.r1_resistance(), selection.r2_resistance());
+
+        if r1.is_some_and(|r1| r1 != pair.0) || r2.is_some_and(|r2| r2 != pair.1) {
+            continue;
+        }
+
+        if !pairs.contains(&pair) {
+            pairs.push(pair);
+        }
+    }
+
+    let mut best: Option<((usize, usize, f64), Choice)> = None;
+
+    for (r1, r2) in pairs {
+        let chosen: Choice = candidates
+            .iter()
+            .map(|(band, q_factor, selections)| {
+                selections
+                    .iter()
+                    .filter(|selection| {
+                        selection.r1_resistance() == r1 && selection.r2_resistance() == r2
+                    })
+                    .min_by(|a, b| {
+                        a.error(*band, *q_factor)
+                            .total_cmp(&b.error(*band, *q_factor))
+                    })
+            })
+            .collect();
+        let chosen_cost = cost(candidates, &chosen);
+
+        let is_better = match &best {
+            Some((best_cost, _)) => is_cheaper(chosen_cost, *best_cost),
+            None => true,
+        };
+
+        if is_better {
+            best = Some((chosen_cost, chosen));
+        }
+    }
+
+    if let Some((_, chosen)) = best {
+        chosen
+    } else {
+        vec![None; candidates.len()]
+    }
+}
+
+fn reduce<'a>(candidates: &'a [(f64, f64, Vec<Selection>)], mut chosen: Choice<'a>) -> Choice<'a> {
+    let mut lines = Vec::new();
+    let (mut missing, _, mut error) = cost(candidates, &chosen);
+
+    for selection in chosen.iter().flatten() {
+        tally(&mut lines, selection);
+    }
+
+    loop {
+        let mut improved = false;
+
+        for (index, (band, q_factor, selections)) in candidates.iter().enumerate() {
+            for selection in selections {
+                let current = chosen[index];
+                let trial_lines =
+                    (lines.len() as isize + line_change(&lines, current, selection)) as usize;
+                let mut trial_missing = missing;
+                let mut trial_error = error + selection.error(*band, *q_factor);
+
+                if let Some(current) = current {
+                    trial_error -= current.error(*band, *q_factor);
+                } else {
+                    trial_missing -= 1;
+                }
+
+                if is_cheaper(
+                    (trial_missing, trial_lines, trial_error),
+                    (missing, lines.len(), error),
+                ) {
+                    if let Some(current) = current {
+                        untally(&mut lines, current);
+                    }
+
+                    tally(&mut lines, selection);
+                    chosen[index] = Some(selection);
+                    missing = trial_missing;
+                    error = trial_error;
+                    improved = true;
+                }
+            }
+        }
+
+        if !improved {
+            return chosen;
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn optimize_bands(
+    count: usize,
+    spacing: Spacing,
+    preset: Preset,
+    frequency: Arg,
+    q_factor: Arg,
+    r1: Arg,
+    r2: Arg,
+    c1: Arg,
+    c2: Arg,
+    optimize: Optimize,
+) -> Result<Vec<Band>, CalcError> {
+    let pair = (pinned(&r1), pinned(&r2));
+    let candidates = band_candidates(count, spacing, preset, frequency, q_factor, r1, r2, c1, c2)?;
+
+    let chosen = match optimize {
+        Closest => closest(&candidates),
+        FewestParts => {
+            let from_closest = reduce(&candidates, closest(&candidates));
+            let from_shared = reduce(&candidates, shared(&candidates, pair));
+
+            if is_cheaper(
+                cost(&candidates, &from_shared),
+                cost(&candidates, &from_closest),
+            ) {
+                from_shared
+            } else {
+                from_closest
+            }
+        }
+        SharedResistors => shared(&candidates, pair),
+    };
+
+    Ok(candidates
+        .iter()
+        .zip(chosen)
+        .map(|((band, _, _), selection)| Band::new(*band, selection.cloned()))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sorted_by_kind_then_value() {
+        let bands = [
+            Band::new(100.0, Some(Selection::new(470.0, 100000.0, 220e-9, 10e-9))),
+            Band::new(1000.0, Some(Selection::new(470.0, 47000.0, 22e-9, 1e-9))),
+            Band::new(10000.0, None),
+        ];
+        let lines = bill_of_materials(&bands);
+
+        assert_eq!(
+            lines,
+            vec![
+                BomLine(Resistor(470.0), 2),
+                BomLine(Resistor(47000.0), 1),
+                BomLine(Resistor(100000.0), 1),
+                BomLine(Capacitor(1e-9), 1),
+                BomLine(Capacitor(10e-9), 1),
+                BomLine(Capacitor(22e-9), 1),
+                BomLine(Capacitor(220e-9), 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn ranks_inductors_last() {
+        let mut parts = [Inductor(1.0), Capacitor(1e-9), Resistor(1e6), Inductor(0.1)];
+
+        parts.sort_by(compare);
+
+        assert_eq!(
+            parts,
+            [Resistor(1e6), Capacitor(1e-9), Inductor(0.1), Inductor(1.0)]
+        );
+    }
+
+    fn optimized(optimize: Optimize) -> Vec<Band> {
+        optimize_bands(
+            10,
+            Octave,
+            Preset::Custom,
+            ArgWithTolerance(31.25, 0.05),
+            ArgWithTolerance(1.4, 0.1),
+            ArgWithSeries(Series::E12, 1000.0, 10000.0),
+            ArgWithSeries(Series::E12, 10000.0, 1000000.0),
+            ArgWithSeries(Series::E6, 1e-9, 10e-6),
+            ArgWithSeries(Series::E6, 1e-9, 10e-6),
+            optimize,
+        )
+        .unwrap()
+    }
+
+    fn pair(band: &Band) -> Option<(f64, f64)> {
+        band.selection()
+            .map(|selection| (selection.r1_resistance(), selection.r2_resistance()))
+    }
+
+    #[test]
+    fn fewest_parts_never_adds_lines() {
+        let closest = optimized(Closest);
+        let fewest = optimized(FewestParts);
+        let found = |bands: &[Band]| {
+            bands
+                .iter()
+                .filter(|band| band.selection().is_some())
+                .count()
+        };
+
+        assert_eq!(found(&fewest), found(&closest));
+        assert!(bill_of_materials(&fewest).len() <= bill_of_materials(&closest).len());
+    }
+
+    #[test]
+    fn shared_resistors_use_one_pair() {
+        let bands = optimized(SharedResistors);
+        let pairs = bands.iter().filter_map(pair).collect::<Vec<_>>();
+
+        assert!(!pairs.is_empty());
+        assert!(pairs.iter().all(|found| *found == pairs[0]));
+    }
+
+    #[test]
+    fn pinned_pair_is_kept() {
+        let candidates = band_candidates(
+            10,
+            Octave,
+            Preset::Custom,
+            ArgWithTolerance(31.25, 0.05),
+            ArgWithTolerance(1.4, 0.1),
+            ArgWithSeries(Series::E12, 1000.0, 10000.0),
+            ArgWithSeries(Series::E12, 10000.0, 1000000.0),
+            ArgWithSeries(Series::E6, 1e-9, 10e-6),
+            ArgWithSeries(Series::E6, 1e-9, 10e-6),
+        )
+        .unwrap();
+        let free = shared(&candidates, (None, None));
+        let free_pair = free
+            .iter()
+            .flatten()
+            .map(|selection| (selection.r1_resistance(), selection.r2_resistance()))
+            .next()
+            .unwrap();
+        let (r1, r2) = candidates
+            .iter()
+            .flat_map(|(_, _, selections)| selections)
+            .map(|selection| (selection.r1_resistance(), selection.r2_resistance()))
+            .find(|found| *found != free_pair)
+            .unwrap();
+        let pinned = shared(&candidates, (Some(r1), Some(r2)));
+
+        assert!(pinned.iter().any(|selection| selection.is_some()));
+        assert!(pinned.iter().flatten().all(|selection| {
+            selection.r1_resistance() == r1 && selection.r2_resistance() == r2
+        }));
+    }
+
+    #[test]
+    fn reduce_tracks_lines_in_place() {
+        let selection = Selection::new(1000.0, 10000.0, 10e-9, 1e-9);
+        let shares_r1 = Selection::new(1000.0, 22000.0, 10e-9, 2.2e-9);
+        let mut lines = Vec::new();
+
+        tally(&mut lines, &selection);
+
+        assert_eq!(line_change(&lines, None, &selection), 0);
+        assert_eq!(line_change(&lines, None, &shares_r1), 2);
+        assert_eq!(line_change(&lines, Some(&selection), &shares_r1), 0);
+        assert_eq!(line_change(&[], None, &shares_r1), 4);
+    }
+}
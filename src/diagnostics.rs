@@ -0,0 +1,137 @@
+use crate::targets::*;
+use crate::*;
+use serde::{Deserialize, Serialize};
+pub use Violation::*;
+
+const NEAR_MISSES: usize = 5;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Violation {
+    MissedFrequency,
+    MissedQFactor,
+    MissedBoth,
+    MissedTarget(Quantity),
+    MissedTargets(Quantity, Quantity),
+}
+
+impl Violation {
+    pub fn as_str(&self) -> String {
+        match self {
+            MissedFrequency => "frequency".into(),
+            MissedQFactor => "q factor".into(),
+            MissedBoth => "frequency and q factor".into(),
+            MissedTarget(quantity) => quantity.as_str().into(),
+            MissedTargets(first, second) => format!("{} and {}", first.as_str(), second.as_str()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NearMiss(Selection, Violation, f64);
+
+impl NearMiss {
+    pub fn selection(&self) -> &Selection {
+        &self.0
+    }
+
+    pub fn violation(&self) -> &Violation {
+        &self.1
+    }
+
+    pub fn distance(&self) -> f64 {
+        self.2
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Diagnostics {
+    pub(crate) table_sizes: [usize; 4],
+    pub(crate) combinations: usize,
+    pub(crate) inductance_rejections: usize,
+    pub(crate) evaluated: usize,
+    pub(crate) frequency_rejections: usize,
+    pub(crate) q_factor_rejections: usize,
+    pub(crate) bandwidth_rejections: usize,
+    pub(crate) accepted: usize,
+    pub(crate) near_misses: Vec<NearMiss>,
+}
+
+impl Diagnostics {
+    pub fn table_sizes(&self) -> [usize; 4] {
+        self.table_sizes
+    }
+
+    pub fn combinations(&self) -> usize {
+        self.combinations
+    }
+
+    pub fn inductance_rejections(&self) -> usize {
+        self.inductance_rejections
+    }
+
+    pub fn evaluated(&self) -> usize {
+        self.evaluated
+    }
+
+    pub fn frequency_rejections(&self) -> usize {
+        self.frequency_rejections
+    }
+
+    pub fn q_factor_rejections(&self) -> usize {
+        self.q_factor_rejections
+    }
+
+    pub fn bandwidth_rejections(&self) -> usize {
+        self.bandwidth_rejections
+    }
+
+    pub fn accepted(&self) -> usize {
+        self.accepted
+    }
+
+    pub fn near_misses(&self) -> &[NearMiss] {
+        &self.near_misses
+    }
+
+    pub fn merge(&mut self, other: Diagnostics) {
+        self.table_sizes = other.table_sizes;
+        self.combinations += other.combinations;
+        self.inductance_rejections += other.inductance_rejections;
+        self.evaluated += other.evaluated;
+        self.frequency_rejections += other.frequency_rejections;
+        self.q_factor_rejections += other.q_factor_rejections;
+        self.bandwidth_rejections += other.bandwidth_rejections;
+        self.accepted += other.accepted;
+
+        for NearMiss(selection, violation, distance) in other.near_misses {
+            self.miss(selection, violation, distance);
+        }
+    }
+
+    pub(crate) fn miss(&mut self, selection: Selection, violation: Violation, distance: f64) {
+        if self.near_misses.len() < NEAR_MISSES
+            || self
+                .near_misses
+                .last()
+                .is_some_and(|near_miss| distance < near_miss.2)
+        {
+            let index = self
+                .near_misses
+                .partition_point(|near_miss| near_miss.2 <= distance);
+
+            self.near_misses
+                .insert(index, NearMiss(selection, violation, distance));
+            self.near_misses.truncate(NEAR_MISSES);
+        }
+    }
+}
+
+pub(crate) fn outside(value: f64, minimum: f64, maximum: f64, target: f64) -> f64 {
+    if value < minimum {
+        (minimum - value) / target
+    } else if value > maximum {
+        (value - maximum) / target
+    } else {
+        0.0
+    }
+}
@@ -0,0 +1,87 @@
+use crate::response::*;
+use crate::*;
+
+pub const DEFAULT_POT: f64 = 10000.0;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct BoostCut(f64, f64);
+
+impl BoostCut {
+    pub fn boost_db(&self) -> f64 {
+        self.0
+    }
+
+    pub fn cut_db(&self) -> f64 {
+        self.1
+    }
+}
+
+pub fn branch_impedance(selection: &Selection, frequency: f64) -> Complex {
+    let r1 = selection.r1_resistance();
+    let r2 = selection.r2_resistance();
+    let c1 = selection.c1_capacitance();
+    let c2 = selection.c2_capacitance();
+    let s = Complex::new(0.0, 2.0 * PI * frequency);
+    let one = Complex::from(1.0);
+    let capacitor = one / (s * c1.into());
+    let gyrator = Complex::from(r1) * (one + s * (r2 * c2).into()) / (one + s * (r1 * c2).into());
+
+    capacitor + gyrator
+}
+
+pub fn slider_gain(selection: &Selection, pot: f64, position: f64, frequency: f64) -> Complex {
+    let branch = branch_impedance(selection, frequency);
+    let position = position.clamp(0.0, 1.0);
+    let shared = Complex::from(position * (1.0 - position) * pot) + branch;
+
+    (shared + (pot * position).into()) / (shared + (pot * (1.0 - position)).into())
+}
+
+pub fn boost_cut(selection: &Selection, pot: f64) -> Result<BoostCut, CalcError> {
+    validate_value("pot", pot)?;
+
+    let frequency = selection.frequency();
+    let boost = slider_gain(selection, pot, 1.0, frequency);
+    let cut = slider_gain(selection, pot, 0.0, frequency);
+
+    Ok(BoostCut(
+        20.0 * boost.magnitude().log10(),
+        20.0 * cut.magnitude().log10(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn selection() -> Selection {
+        Selection::new(1000.0, 100000.0, 100e-9, 10e-9)
+    }
+
+    #[test]
+    fn full_boost_at_resonance() {
+        let selection = selection();
+        let branch = branch_impedance(&selection, selection.frequency()).magnitude();
+        let gain = boost_cut(&selection, DEFAULT_POT).unwrap();
+
+        assert!((gain.boost_db() - 20.0 * (1.0 + DEFAULT_POT / branch).log10()).abs() < 0.01);
+    }
+
+    #[test]
+    fn cut_mirrors_boost() {
+        for pot in [1000.0, DEFAULT_POT, 100000.0] {
+            let gain = boost_cut(&selection(), pot).unwrap();
+
+            assert!(gain.boost_db() > 0.0);
+            assert!((gain.boost_db() + gain.cut_db()).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn centred_slider_is_flat() {
+        let selection = selection();
+        let gain = slider_gain(&selection, DEFAULT_POT, 0.5, selection.frequency());
+
+        assert!((gain.magnitude() - 1.0).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,79 @@
+use crate::ladder::MAX_ORDER;
+use crate::matching::MIN_MEASURED;
+use crate::units::*;
+use std::fmt;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum CalcError {
+    MissingValue(&'static str),
+    NonFinite(&'static str),
+    NotPositive(&'static str, f64),
+    InvalidRange(&'static str, f64, f64),
+    InvalidTolerance(&'static str, f64),
+    EmptyTable(&'static str, f64, f64),
+    Cancelled,
+    Worker(String),
+    SingularCircuit(f64),
+    InvalidOrder(usize),
+    InvalidTaper,
+    InvalidRealization,
+    NotEnoughParts(&'static str, usize),
+}
+
+impl fmt::Display for CalcError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CalcError::MissingValue(name) => write!(f, "{} needs a value", name),
+            CalcError::NonFinite(name) => write!(f, "{} is not a finite number", name),
+            CalcError::NotPositive(name, value) => {
+                write!(f, "{} must be above zero, got {}", name, value)
+            }
+            CalcError::InvalidRange(name, min, max) => write!(
+                f,
+                "{} range minimum {} is above its maximum {}",
+                name,
+                format_units(*min),
+                format_units(*max)
+            ),
+            CalcError::InvalidTolerance(name, tolerance) => write!(
+                f,
+                "{} tolerance must be between 0% and 100%, got {}%",
+                name,
+                tolerance * 100.0
+            ),
+            CalcError::EmptyTable(name, min, max) => write!(
+                f,
+                "{} has no series values between {} and {}",
+                name,
+                format_units(*min),
+                format_units(*max)
+            ),
+            CalcError::Cancelled => write!(f, "search was cancelled"),
+            CalcError::Worker(message) => write!(f, "search worker failed: {}", message),
+            CalcError::SingularCircuit(frequency) => write!(
+                f,
+                "circuit could not be solved at {}hz",
+                format_units(*frequency)
+            ),
+            CalcError::InvalidOrder(order) => write!(
+                f,
+                "filter order must be between 1 and {}, got {}",
+                MAX_ORDER, order
+            ),
+            CalcError::InvalidTaper => write!(
+                f,
+                "taper points must be rotation:resistance percentages that rise from 0% to 100%"
+            ),
+            CalcError::InvalidRealization => {
+                write!(f, "the bruton fdnr realization needs a low pass ladder")
+            }
+            CalcError::NotEnoughParts(name, count) => write!(
+                f,
+                "{} needs at least {} measured parts, got {}",
+                name, MIN_MEASURED, count
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CalcError {}
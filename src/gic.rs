@@ -0,0 +1,201 @@
+use crate::bom::*;
+use crate::response::*;
+use crate::*;
+pub use GicKind::*;
+
+pub const GIC_OPTIONS: [GicKind; 2] = [FloatingInductor, Fdnr];
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum GicKind {
+    FloatingInductor,
+    Fdnr,
+}
+
+impl GicKind {
+    pub fn as_str(&self) -> String {
+        match self {
+            FloatingInductor => "floating inductor".into(),
+            Fdnr => "fdnr".into(),
+        }
+    }
+
+    pub fn value(&self, resistance: f64, r5: f64, capacitance: f64) -> f64 {
+        match self {
+            FloatingInductor => capacitance * resistance * r5,
+            Fdnr => capacitance * capacitance * r5,
+        }
+    }
+}
+
+impl From<String> for GicKind {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "fdnr" => Fdnr,
+            _ => FloatingInductor,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Gic(GicKind, f64, f64, f64, f64);
+
+impl Gic {
+    pub fn new(kind: GicKind, resistance: f64, r5: f64, capacitance: f64) -> Gic {
+        let value = kind.value(resistance, r5, capacitance);
+
+        Gic(kind, value, resistance, r5, capacitance)
+    }
+
+    pub fn kind(&self) -> &GicKind {
+        &self.0
+    }
+
+    pub fn value(&self) -> f64 {
+        self.1
+    }
+
+    pub fn resistance(&self) -> f64 {
+        self.2
+    }
+
+    pub fn r5_resistance(&self) -> f64 {
+        self.3
+    }
+
+    pub fn capacitance(&self) -> f64 {
+        self.4
+    }
+
+    pub fn error(&self, value: f64) -> f64 {
+        ((self.1 - value) / value).abs()
+    }
+
+    pub fn impedance(&self, frequency: f64) -> Complex {
+        let s = Complex::new(0.0, 2.0 * PI * frequency);
+
+        match self.0 {
+            FloatingInductor => s * self.1.into(),
+            Fdnr => Complex::from(1.0) / (s * s * self.1.into()),
+        }
+    }
+
+    pub fn parts(&self) -> Vec<(&'static str, Part)> {
+        match self.0 {
+            FloatingInductor => vec![
+                ("r1a", Resistor(self.2)),
+                ("r2a", Resistor(self.2)),
+                ("r3a", Resistor(self.2)),
+                ("c4a", Capacitor(self.4)),
+                ("r5", Resistor(self.3)),
+                ("r1b", Resistor(self.2)),
+                ("r2b", Resistor(self.2)),
+                ("r3b", Resistor(self.2)),
+                ("c4b", Capacitor(self.4)),
+            ],
+            Fdnr => vec![
+                ("c1", Capacitor(self.4)),
+                ("r2", Resistor(self.3)),
+                ("r3", Resistor(self.3)),
+                ("r4", Resistor(self.3)),
+                ("c5", Capacitor(self.4)),
+            ],
+        }
+    }
+}
+
+pub fn calculate_gic(
+    kind: &GicKind,
+    value: Arg,
+    resistance: Arg,
+    capacitance: Arg,
+) -> Result<Vec<Gic>, CalcError> {
+    value.validate("value")?;
+
+    let (target, minimum, maximum) = value_to_tolerance(value);
+    let resistance_table = resistance.to_checked_table("resistance")?;
+    let capacitance_table = capacitance.to_checked_table("capacitance")?;
+    let mut results = Vec::new();
+
+    for resistance_value in &resistance_table {
+        for r5_value in &resistance_table {
+            if *kind == Fdnr && r5_value != resistance_value {
+                continue;
+            }
+
+            for capacitance_value in &capacitance_table {
+                let gic = Gic::new(
+                    kind.clone(),
+                    *resistance_value,
+                    *r5_value,
+                    *capacitance_value,
+                );
+
+                if gic.1 >= minimum && gic.1 <= maximum {
+                    results.push(gic);
+                }
+            }
+        }
+    }
+
+    results.sort_by(|a, b| a.error(target).total_cmp(&b.error(target)));
+
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::series::*;
+
+    #[test]
+    fn floating_inductor_impedance() {
+        let gic = Gic::new(FloatingInductor, 1000.0, 1000.0, 100e-9);
+        let impedance = gic.impedance(1000.0);
+
+        assert!((gic.value() - 0.1).abs() < 1e-12);
+        assert!((impedance.magnitude() - 628.3185).abs() < 1e-3);
+        assert!((impedance.phase() - PI / 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn fdnr_impedance() {
+        let gic = Gic::new(Fdnr, 1000.0, 1000.0, 1e-6);
+        let impedance = gic.impedance(1000.0);
+
+        assert!((gic.value() - 1e-9).abs() < 1e-21);
+        assert!((impedance.magnitude() - 25.3303).abs() < 1e-3);
+        assert!((impedance.phase().abs() - PI).abs() < 1e-9);
+    }
+
+    #[test]
+    fn closest_value_first() {
+        let found = calculate_gic(
+            &FloatingInductor,
+            ArgWithTolerance(0.1, 0.05),
+            ArgWithSeries(Series::E12, 1000.0, 10000.0),
+            ArgWithSeries(Series::E6, 10e-9, 1e-6),
+        )
+        .unwrap();
+
+        assert!(found[0].error(0.1) < 1e-9);
+        assert!(found
+            .windows(2)
+            .all(|pair| pair[0].error(0.1) <= pair[1].error(0.1)));
+    }
+
+    #[test]
+    fn fdnr_uses_equal_resistors() {
+        let found = calculate_gic(
+            &Fdnr,
+            ArgWithTolerance(1e-9, 0.1),
+            ArgWithSeries(Series::E12, 1000.0, 10000.0),
+            ArgWithSeries(Series::E6, 10e-9, 1e-6),
+        )
+        .unwrap();
+
+        assert!(!found.is_empty());
+        assert!(found
+            .iter()
+            .all(|gic| gic.resistance() == gic.r5_resistance()));
+    }
+}
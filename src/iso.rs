@@ -0,0 +1,136 @@
+use crate::bands::*;
+pub use Preset::*;
+
+pub const PRESET_OPTIONS: [Preset; 3] = [Custom, IsoNominal, IsoExact];
+
+// iso 266 nominal third octave frequencies, one decade of the r10 series
+const NOMINAL_VALUES: [f64; 10] = [1.0, 1.25, 1.6, 2.0, 2.5, 3.15, 4.0, 5.0, 6.3, 8.0];
+
+const REFERENCE_FREQUENCY: f64 = 1000.0;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Preset {
+    Custom,
+    IsoNominal,
+    IsoExact,
+}
+
+impl Preset {
+    pub fn as_str(&self) -> String {
+        match self {
+            Custom => "custom".into(),
+            IsoNominal => "iso nominal".into(),
+            IsoExact => "iso exact".into(),
+        }
+    }
+}
+
+impl From<String> for Preset {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "custom" => Custom,
+            "iso nominal" => IsoNominal,
+            "iso exact" => IsoExact,
+            _ => Custom,
+        }
+    }
+}
+
+pub fn exact_frequency(band: i32) -> f64 {
+    REFERENCE_FREQUENCY * 10f64.powf(band as f64 / 10.0)
+}
+
+pub fn nominal_frequency(band: i32) -> f64 {
+    let value = NOMINAL_VALUES[band.rem_euclid(10) as usize] * REFERENCE_FREQUENCY;
+    let decade = band.div_euclid(10);
+
+    if decade >= 0 {
+        value * 10f64.powi(decade)
+    } else {
+        value / 10f64.powi(-decade)
+    }
+}
+
+fn band_numbers(spacing: &Spacing, min: f64, max: f64) -> impl Iterator<Item = i32> {
+    let step = (spacing.octaves() * 3.0).round() as i32;
+    let first = (10.0 * (min / REFERENCE_FREQUENCY).log10()).floor() as i32;
+    let last = (10.0 * (max / REFERENCE_FREQUENCY).log10()).ceil() as i32;
+
+    (first..=last).filter(move |band| band.rem_euclid(step) == 0)
+}
+
+pub fn exact_frequencies(spacing: Spacing, min: f64, max: f64) -> Vec<f64> {
+    band_numbers(&spacing, min, max)
+        .map(exact_frequency)
+        .filter(|frequency| *frequency >= min && *frequency <= max)
+        .collect()
+}
+
+pub fn nominal_frequencies(spacing: Spacing, min: f64, max: f64) -> Vec<f64> {
+    band_numbers(&spacing, min, max)
+        .map(nominal_frequency)
+        .filter(|frequency| *frequency >= min && *frequency <= max)
+        .collect()
+}
+
+pub fn preset_frequencies(preset: Preset, count: usize, spacing: Spacing, lowest: f64) -> Vec<f64> {
+    let half_band = 2f64.powf(spacing.octaves() / 2.0);
+    let min = lowest / half_band;
+    let max = lowest * 2f64.powf(spacing.octaves() * count as f64) * half_band;
+
+    let frequencies = match preset {
+        Custom => band_frequencies(count, spacing, lowest),
+        IsoNominal => band_numbers(&spacing, min, max)
+            .filter(|band| exact_frequency(*band) >= min)
+            .map(nominal_frequency)
+            .collect(),
+        IsoExact => band_numbers(&spacing, min, max)
+            .map(exact_frequency)
+            .filter(|frequency| *frequency >= min)
+            .collect(),
+    };
+
+    frequencies.into_iter().take(count).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reference_bands() {
+        assert_eq!(nominal_frequency(0), 1000.0);
+        assert_eq!(exact_frequency(0), 1000.0);
+        assert!((nominal_frequency(-15) - 31.5).abs() < 1e-9);
+        assert!((exact_frequency(-15) - 31.6228).abs() < 1e-4);
+    }
+
+    #[test]
+    fn octave_presets() {
+        let nominal = preset_frequencies(IsoNominal, 10, Octave, 31.5);
+        let exact = preset_frequencies(IsoExact, 10, Octave, 31.5);
+
+        assert_eq!(
+            nominal,
+            vec![31.5, 63.0, 125.0, 250.0, 500.0, 1000.0, 2000.0, 4000.0, 8000.0, 16000.0]
+        );
+        assert_eq!(exact.len(), 10);
+        assert!(exact
+            .windows(2)
+            .all(|pair| (pair[1] / pair[0] - 10f64.powf(0.3)).abs() < 1e-9));
+    }
+
+    #[test]
+    fn third_octave_presets() {
+        let nominal = preset_frequencies(IsoNominal, 31, ThirdOctave, 20.0);
+        let exact = preset_frequencies(IsoExact, 31, ThirdOctave, 20.0);
+
+        assert_eq!(nominal.len(), 31);
+        assert_eq!(nominal[0], 20.0);
+        assert_eq!(nominal[30], 20000.0);
+        assert_eq!(exact.len(), 31);
+        assert!(exact
+            .windows(2)
+            .all(|pair| (pair[1] / pair[0] - 10f64.powf(0.1)).abs() < 1e-9));
+    }
+}
@@ -0,0 +1,554 @@
+use crate::bom::*;
+use crate::gic::*;
+use crate::targets::*;
+use crate::*;
+pub use Approximation::*;
+pub use LadderElement::*;
+pub use Pass::*;
+pub use Realization::*;
+
+pub const MAX_ORDER: usize = 10;
+
+pub const APPROXIMATION_OPTIONS: [Approximation; 2] = [Butterworth, Chebyshev];
+
+pub const PASS_OPTIONS: [Pass; 2] = [HighPass, LowPass];
+
+pub const REALIZATION_OPTIONS: [Realization; 2] = [SimulatedInductors, Bruton];
+
+pub const ORDER_OPTIONS: [usize; MAX_ORDER] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+
+pub const RIPPLE_OPTIONS: [f64; 6] = [0.01, 0.1, 0.25, 0.5, 1.0, 3.0];
+
+pub const SIMULATED_TOLERANCE: f64 = 0.05;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Approximation {
+    Butterworth,
+    Chebyshev,
+}
+
+impl Approximation {
+    pub fn as_str(&self) -> String {
+        match self {
+            Butterworth => "butterworth".into(),
+            Chebyshev => "chebyshev".into(),
+        }
+    }
+}
+
+impl From<String> for Approximation {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "chebyshev" => Chebyshev,
+            _ => Butterworth,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Pass {
+    HighPass,
+    LowPass,
+}
+
+impl Pass {
+    pub fn as_str(&self) -> String {
+        match self {
+            HighPass => "high pass".into(),
+            LowPass => "low pass".into(),
+        }
+    }
+}
+
+impl From<String> for Pass {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "low pass" => LowPass,
+            _ => HighPass,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Realization {
+    SimulatedInductors,
+    Bruton,
+}
+
+impl Realization {
+    pub fn as_str(&self) -> String {
+        match self {
+            SimulatedInductors => "simulated inductors".into(),
+            Bruton => "bruton fdnr".into(),
+        }
+    }
+}
+
+impl From<String> for Realization {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "bruton fdnr" => Bruton,
+            _ => SimulatedInductors,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum LadderElement {
+    SeriesCapacitor(f64),
+    ShuntCapacitor(f64),
+    SeriesInductor(f64, Option<Gic>),
+    ShuntInductor(f64, Option<Selection>),
+    SeriesResistor(f64),
+    ShuntFdnr(f64, Option<Gic>),
+}
+
+impl LadderElement {
+    pub fn as_str(&self) -> String {
+        match self {
+            SeriesCapacitor(_) => "series capacitor".into(),
+            ShuntCapacitor(_) => "shunt capacitor".into(),
+            SeriesInductor(_, _) => "series inductor".into(),
+            ShuntInductor(_, _) => "shunt inductor".into(),
+            SeriesResistor(_) => "series resistor".into(),
+            ShuntFdnr(_, _) => "shunt fdnr".into(),
+        }
+    }
+
+    pub fn value(&self) -> f64 {
+        match self {
+            SeriesCapacitor(value) | ShuntCapacitor(value) => *value,
+            SeriesInductor(value, _) | ShuntInductor(value, _) => *value,
+            SeriesResistor(value) | ShuntFdnr(value, _) => *value,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Component(String, Part);
+
+impl Component {
+    pub fn name(&self) -> &str {
+        &self.0
+    }
+
+    pub fn part(&self) -> &Part {
+        &self.1
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Ladder(Part, Part, Vec<LadderElement>);
+
+impl Ladder {
+    pub fn source(&self) -> &Part {
+        &self.0
+    }
+
+    pub fn load(&self) -> &Part {
+        &self.1
+    }
+
+    pub fn elements(&self) -> &[LadderElement] {
+        &self.2
+    }
+
+    pub fn components(&self) -> Vec<Component> {
+        let termination = |name: &str, part: &Part| match part {
+            Capacitor(_) => Component(format!("c{}", name), part.clone()),
+            Inductor(_) => Component(format!("l{}", name), part.clone()),
+            Resistor(_) => Component(format!("r{}", name), part.clone()),
+        };
+        let mut components = vec![termination("s", &self.0)];
+
+        for (index, element) in self.2.iter().enumerate() {
+            let position = index + 1;
+
+            match element {
+                SeriesCapacitor(value) | ShuntCapacitor(value) => {
+                    components.push(Component(format!("c{}", position), Capacitor(*value)));
+                }
+                SeriesInductor(value, None) | ShuntInductor(value, None) => {
+                    components.push(Component(format!("l{}", position), Inductor(*value)));
+                }
+                SeriesInductor(_, Some(gic)) => {
+                    components.extend(
+                        gic.parts()
+                            .into_iter()
+                            .map(|(name, part)| Component(format!("l{} {}", position, name), part)),
+                    );
+                }
+                SeriesResistor(value) => {
+                    components.push(Component(format!("r{}", position), Resistor(*value)));
+                }
+                ShuntFdnr(_, Some(gic)) => {
+                    components.extend(
+                        gic.parts()
+                            .into_iter()
+                            .map(|(name, part)| Component(format!("d{} {}", position, name), part)),
+                    );
+                }
+                ShuntFdnr(_, None) => {}
+                ShuntInductor(_, Some(selection)) => components.extend([
+                    Component(
+                        format!("l{} r1", position),
+                        Resistor(selection.r1_resistance()),
+                    ),
+                    Component(
+                        format!("l{} r2", position),
+                        Resistor(selection.r2_resistance()),
+                    ),
+                    Component(
+                        format!("l{} c2", position),
+                        Capacitor(selection.c2_capacitance()),
+                    ),
+                ]),
+            }
+        }
+
+        components.push(termination("l", &self.1));
+        components
+    }
+}
+
+pub fn prototype(
+    approximation: &Approximation,
+    order: usize,
+    ripple: f64,
+) -> Result<Vec<f64>, CalcError> {
+    if order == 0 || order > MAX_ORDER {
+        return Err(CalcError::InvalidOrder(order));
+    }
+
+    let n = order as f64;
+    let angle = |k: usize| ((2 * k - 1) as f64) * PI / (2.0 * n);
+
+    match approximation {
+        Butterworth => {
+            let mut values = (1..=order)
+                .map(|k| 2.0 * angle(k).sin())
+                .collect::<Vec<_>>();
+
+            values.push(1.0);
+            Ok(values)
+        }
+        Chebyshev => {
+            validate_value("ripple", ripple)?;
+
+            let beta = (1.0 / (ripple / 17.37).tanh()).ln();
+            let gamma = (beta / (2.0 * n)).sinh();
+            let mut values = vec![2.0 * angle(1).sin() / gamma];
+
+            for k in 2..=order {
+                let a = angle(k - 1).sin() * angle(k).sin();
+                let b = gamma * gamma + ((k - 1) as f64 * PI / n).sin().powi(2);
+
+                values.push(4.0 * a / (b * values[k - 2]));
+            }
+
+            if order.is_multiple_of(2) {
+                values.push((1.0 / (beta / 4.0).tanh()).powi(2));
+            } else {
+                values.push(1.0);
+            }
+
+            Ok(values)
+        }
+    }
+}
+
+pub fn denormalize(
+    prototype: &[f64],
+    pass: &Pass,
+    cutoff: f64,
+    impedance: f64,
+) -> Result<Ladder, CalcError> {
+    validate_value("cutoff", cutoff)?;
+    validate_value("impedance", impedance)?;
+
+    let (load, values) = prototype.split_last().ok_or(CalcError::InvalidOrder(0))?;
+    let omega = 2.0 * PI * cutoff;
+    let elements = values
+        .iter()
+        .enumerate()
+        .map(|(index, value)| match (pass, index.is_multiple_of(2)) {
+            (LowPass, true) => ShuntCapacitor(value / (omega * impedance)),
+            (LowPass, false) => SeriesInductor(value * impedance / omega, None),
+            (HighPass, true) => ShuntInductor(impedance / (omega * value), None),
+            (HighPass, false) => SeriesCapacitor(1.0 / (omega * value * impedance)),
+        })
+        .collect::<Vec<_>>();
+    let load = if values.len().is_multiple_of(2) {
+        impedance / load
+    } else {
+        impedance * load
+    };
+
+    Ok(Ladder(Resistor(impedance), Resistor(load), elements))
+}
+
+pub fn bruton(ladder: &Ladder, cutoff: f64) -> Result<Ladder, CalcError> {
+    validate_value("cutoff", cutoff)?;
+
+    let omega = 2.0 * PI * cutoff;
+    let termination = |part: &Part| match part {
+        Resistor(value) => Ok(Capacitor(1.0 / (omega * value))),
+        _ => Err(CalcError::InvalidRealization),
+    };
+    let elements = ladder
+        .elements()
+        .iter()
+        .map(|element| match element {
+            SeriesInductor(value, _) => Ok(SeriesResistor(omega * value)),
+            ShuntCapacitor(value) => Ok(ShuntFdnr(value / omega, None)),
+            _ => Err(CalcError::InvalidRealization),
+        })
+        .collect::<Result<Vec<_>, CalcError>>()?;
+
+    Ok(Ladder(
+        termination(ladder.source())?,
+        termination(ladder.load())?,
+        elements,
+    ))
+}
+
+pub fn tolerance_window(cutoff: &Arg, value: f64) -> Arg {
+    match cutoff {
+        ArgWithTolerance(_, tolerance) | ArgWithBandwidth(_, _, tolerance) => {
+            ArgWithTolerance(value, tolerance.max(SIMULATED_TOLERANCE))
+        }
+        ArgWithExact(_) | ArgWithSeries(_, _, _) => ArgWithTolerance(value, SIMULATED_TOLERANCE),
+    }
+}
+
+pub fn simulated_inductor(
+    inductance: f64,
+    cutoff: &Arg,
+    resistance: &Arg,
+    capacitance: &Arg,
+) -> Result<Option<Selection>, CalcError> {
+    let (results, _) = calculate_targets(
+        &FrequencyAndInductance,
+        tolerance_window(cutoff, cutoff.target()),
+        tolerance_window(cutoff, inductance),
+        0.0,
+        resistance.clone(),
+        resistance.clone(),
+        capacitance.clone(),
+        capacitance.clone(),
+    )?;
+    let error = |selection: &Selection| ((selection.inductance() - inductance) / inductance).abs();
+
+    Ok(results.into_iter().max_by(|a, b| {
+        a.q_factor()
+            .total_cmp(&b.q_factor())
+            .then_with(|| error(b).total_cmp(&error(a)))
+    }))
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn ladder(
+    approximation: &Approximation,
+    pass: &Pass,
+    realization: &Realization,
+    order: usize,
+    ripple: f64,
+    cutoff: Arg,
+    impedance: f64,
+    resistance: Arg,
+    capacitance: Arg,
+) -> Result<Ladder, CalcError> {
+    cutoff.validate("cutoff")?;
+
+    let values = prototype(approximation, order, ripple)?;
+    let denormalized = denormalize(&values, pass, cutoff.target(), impedance)?;
+    let Ladder(source, load, elements) = match realization {
+        SimulatedInductors => denormalized,
+        Bruton => bruton(&denormalized, cutoff.target())?,
+    };
+    let elements = elements
+        .into_iter()
+        .map(|element| match element {
+            SeriesInductor(inductance, _) => Ok(SeriesInductor(
+                inductance,
+                calculate_gic(
+                    &FloatingInductor,
+                    tolerance_window(&cutoff, inductance),
+                    resistance.clone(),
+                    capacitance.clone(),
+                )?
+                .into_iter()
+                .next(),
+            )),
+            ShuntInductor(inductance, _) => Ok(ShuntInductor(
+                inductance,
+                simulated_inductor(inductance, &cutoff, &resistance, &capacitance)?,
+            )),
+            ShuntFdnr(value, _) => Ok(ShuntFdnr(
+                value,
+                calculate_gic(
+                    &Fdnr,
+                    tolerance_window(&cutoff, value),
+                    resistance.clone(),
+                    capacitance.clone(),
+                )?
+                .into_iter()
+                .next(),
+            )),
+            element => Ok(element),
+        })
+        .collect::<Result<Vec<_>, CalcError>>()?;
+
+    Ok(Ladder(source, load, elements))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::{FRAC_1_SQRT_2, SQRT_2};
+
+    fn assert_values(actual: &[f64], expected: &[f64]) {
+        assert_eq!(actual.len(), expected.len());
+
+        for (actual, expected) in actual.iter().zip(expected) {
+            assert!(
+                (actual - expected).abs() < 1e-3,
+                "{} != {}",
+                actual,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn butterworth_prototype() {
+        assert_values(
+            &prototype(&Butterworth, 3, 0.0).unwrap(),
+            &[1.0, 2.0, 1.0, 1.0],
+        );
+        assert_values(
+            &prototype(&Butterworth, 2, 0.0).unwrap(),
+            &[SQRT_2, SQRT_2, 1.0],
+        );
+    }
+
+    #[test]
+    fn chebyshev_prototype() {
+        assert_values(
+            &prototype(&Chebyshev, 3, 0.5).unwrap(),
+            &[1.5963, 1.0967, 1.5963, 1.0],
+        );
+        assert_values(
+            &prototype(&Chebyshev, 2, 0.5).unwrap(),
+            &[1.4029, FRAC_1_SQRT_2, 1.9841],
+        );
+    }
+
+    #[test]
+    fn invalid_order() {
+        assert_eq!(
+            prototype(&Butterworth, 0, 0.0),
+            Err(CalcError::InvalidOrder(0))
+        );
+        assert_eq!(
+            prototype(&Butterworth, MAX_ORDER + 1, 0.0),
+            Err(CalcError::InvalidOrder(MAX_ORDER + 1))
+        );
+    }
+
+    #[test]
+    fn exact_cutoff_finds_high_q_gyrator() {
+        let found = ladder(
+            &Butterworth,
+            &HighPass,
+            &SimulatedInductors,
+            3,
+            0.0,
+            ArgWithExact(1000.0),
+            600.0,
+            ArgWithSeries(Series::E24, 100.0, 1000000.0),
+            ArgWithSeries(Series::E12, 1e-9, 1e-6),
+        )
+        .unwrap();
+        let (inductance, selection) = match &found.elements()[0] {
+            ShuntInductor(inductance, Some(selection)) => (*inductance, selection.clone()),
+            element => panic!("{:?}", element),
+        };
+
+        assert!((selection.inductance() / inductance - 1.0).abs() <= SIMULATED_TOLERANCE);
+        assert!((selection.frequency() / 1000.0 - 1.0).abs() <= SIMULATED_TOLERANCE);
+        assert_eq!(selection.r1_resistance(), 100.0);
+        assert!(selection.q_factor() > 5.0);
+    }
+
+    #[test]
+    fn exact_cutoff_finds_gic() {
+        let found = ladder(
+            &Butterworth,
+            &LowPass,
+            &SimulatedInductors,
+            3,
+            0.0,
+            ArgWithExact(1000.0),
+            600.0,
+            ArgWithSeries(Series::E24, 100.0, 1000000.0),
+            ArgWithSeries(Series::E12, 1e-9, 1e-6),
+        )
+        .unwrap();
+
+        match &found.elements()[1] {
+            SeriesInductor(inductance, Some(gic)) => {
+                assert!(gic.error(*inductance) <= SIMULATED_TOLERANCE)
+            }
+            element => panic!("{:?}", element),
+        }
+    }
+
+    #[test]
+    fn bruton_low_pass_uses_fdnrs() {
+        let found = ladder(
+            &Butterworth,
+            &LowPass,
+            &Bruton,
+            3,
+            0.0,
+            ArgWithExact(1000.0),
+            600.0,
+            ArgWithSeries(Series::E24, 100.0, 1000000.0),
+            ArgWithSeries(Series::E12, 1e-9, 1e-6),
+        )
+        .unwrap();
+        let omega = 2.0 * PI * 1000.0;
+
+        assert_eq!(found.source(), &Capacitor(1.0 / (omega * 600.0)));
+        assert_eq!(found.load(), &Capacitor(1.0 / (omega * 600.0)));
+
+        match found.elements() {
+            [ShuntFdnr(first, Some(first_gic)), SeriesResistor(resistance), ShuntFdnr(_, Some(_))] =>
+            {
+                assert!((first - 1.0 / (omega * omega * 600.0)).abs() < 1e-15);
+                assert!(first_gic.error(*first) <= SIMULATED_TOLERANCE);
+                assert!((resistance - 1200.0).abs() < 1e-9);
+            }
+            elements => panic!("{:?}", elements),
+        }
+    }
+
+    #[test]
+    fn bruton_rejects_high_pass() {
+        assert_eq!(
+            ladder(
+                &Butterworth,
+                &HighPass,
+                &Bruton,
+                3,
+                0.0,
+                ArgWithExact(1000.0),
+                600.0,
+                ArgWithSeries(Series::E24, 100.0, 1000000.0),
+                ArgWithSeries(Series::E12, 1e-9, 1e-6),
+            ),
+            Err(CalcError::InvalidRealization)
+        );
+    }
+}
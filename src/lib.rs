@@ -1,6 +1,37 @@
+pub mod bands;
+pub mod bandwidth;
+pub mod bom;
+pub mod diagnostics;
+pub mod eq;
+pub mod error;
+pub mod gic;
+pub mod iso;
+pub mod ladder;
+pub mod matching;
+pub mod mna;
+pub mod monte_carlo;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod parallel;
+pub mod relax;
+pub mod response;
+pub mod rework;
+pub mod sensitivity;
 pub mod series;
+pub mod spice;
+pub mod sweep;
+pub mod taper;
+pub mod targets;
+pub mod tempco;
+pub mod tolerance;
+pub mod topology;
+pub mod tracking;
 pub mod units;
+pub mod worker;
 
+use bandwidth::*;
+use diagnostics::*;
+pub use error::CalcError;
+use serde::{Deserialize, Serialize};
 use series::*;
 use std::f64::consts::PI;
 pub use Arg::*;
@@ -10,11 +41,12 @@ pub const SERIES_OPTIONS: [Series; 4] = [Series::E6, Series::E12, Series::E24, S
 
 pub const TOLERANCE_OPTIONS: [f64; 8] = [0.01, 0.02, 0.05, 0.1, 0.15, 0.2, 0.25, 0.3];
 
-#[derive(Clone, Debug, PartialEq, PartialOrd)]
+#[derive(Clone, Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub enum Arg {
     ArgWithExact(f64),
     ArgWithTolerance(f64, f64),
     ArgWithSeries(Series, f64, f64),
+    ArgWithBandwidth(QMeasure, f64, f64),
 }
 
 impl Arg {
@@ -23,6 +55,68 @@ impl Arg {
             ArgWithExact(value) => vec![*value],
             ArgWithTolerance(value, _) => vec![*value],
             ArgWithSeries(series, min, max) => series_table(series.clone(), *min, *max),
+            ArgWithBandwidth(_, value, _) => vec![*value],
+        }
+    }
+
+    pub fn retarget(&self, target: f64) -> Arg {
+        let scale = target / self.target();
+
+        match self {
+            ArgWithExact(_) => ArgWithExact(target),
+            ArgWithTolerance(_, tolerance) => ArgWithTolerance(target, *tolerance),
+            ArgWithSeries(series, min, max) => {
+                ArgWithSeries(series.clone(), min * scale, max * scale)
+            }
+            ArgWithBandwidth(measure, _, tolerance) => {
+                ArgWithBandwidth(measure.clone(), target, *tolerance)
+            }
+        }
+    }
+
+    pub fn validate(&self, name: &'static str) -> Result<(), CalcError> {
+        match self {
+            ArgWithExact(value) => validate_value(name, *value),
+            ArgWithTolerance(value, tolerance) | ArgWithBandwidth(_, value, tolerance) => {
+                validate_value(name, *value)?;
+                validate_tolerance(name, *tolerance)
+            }
+            ArgWithSeries(_, min, max) => {
+                validate_value(name, *min)?;
+                validate_value(name, *max)?;
+
+                if min > max {
+                    Err(CalcError::InvalidRange(name, *min, *max))
+                } else {
+                    Ok(())
+                }
+            }
+        }
+    }
+
+    pub fn to_checked_table(&self, name: &'static str) -> Result<Vec<f64>, CalcError> {
+        self.validate(name)?;
+
+        let table = self.to_table();
+
+        match self {
+            ArgWithSeries(_, min, max) if table.is_empty() => {
+                Err(CalcError::EmptyTable(name, *min, *max))
+            }
+            _ => Ok(table),
+        }
+    }
+
+    pub fn target(&self) -> f64 {
+        value_to_tolerance(self.clone()).0
+    }
+
+    pub fn to_q_factor(&self, frequency: f64) -> Arg {
+        match self {
+            ArgWithBandwidth(measure, value, tolerance) => {
+                ArgWithTolerance(measure.to_q_factor(*value, frequency), *tolerance)
+            }
+            _ => self.clone(),
         }
     }
 }
@@ -33,6 +127,7 @@ pub enum Input {
     InputWithExact(Option<f64>, Option<f64>),
     InputWithTolerance(Option<f64>, f64, f64),
     InputWithSeries(Series, Option<f64>, Option<f64>, f64, f64),
+    InputWithBandwidth(QMeasure, Option<f64>, f64, f64),
 }
 
 impl Input {
@@ -60,6 +155,10 @@ impl Input {
 
                 Some(ArgWithTolerance(*target, *tolerance))
             }
+            InputWithBandwidth(measure, target, tolerance, fallback) => match target {
+                Some(target) => Some(ArgWithBandwidth(measure.clone(), *target, *tolerance)),
+                None => Some(ArgWithTolerance(*fallback, *tolerance)),
+            },
             InputWithExact(target, fallback) => match (target, fallback) {
                 (Some(target), _) => Some(ArgWithExact(*target)),
                 (None, Some(fallback)) => Some(ArgWithExact(*fallback)),
@@ -69,10 +168,18 @@ impl Input {
     }
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Selection(f64, f64, f64, f64, f64, f64, f64);
 
 impl Selection {
+    pub fn new(r1: f64, r2: f64, c1: f64, c2: f64) -> Selection {
+        let inductance = r1 * r2 * c2;
+        let frequency = 1.0 / (2.0 * PI * (inductance * c1).sqrt());
+        let q_factor = 2.0 * PI * frequency * inductance / r1;
+
+        Selection(frequency, q_factor, inductance, r1, r2, c1, c2)
+    }
+
     pub fn frequency(&self) -> f64 {
         self.0
     }
@@ -100,6 +207,39 @@ impl Selection {
     pub fn c2_capacitance(&self) -> f64 {
         self.6
     }
+
+    pub fn bandwidth(&self) -> f64 {
+        bandwidth_from_q(self.frequency(), self.q_factor())
+    }
+
+    pub fn bandwidth_octaves(&self) -> f64 {
+        octaves_from_q(self.q_factor())
+    }
+
+    pub fn error(&self, frequency: f64, q_factor: f64) -> f64 {
+        ((self.frequency() - frequency) / frequency).abs()
+            + ((self.q_factor() - q_factor) / q_factor).abs()
+    }
+}
+
+fn validate_value(name: &'static str, value: f64) -> Result<(), CalcError> {
+    if !value.is_finite() {
+        Err(CalcError::NonFinite(name))
+    } else if value <= 0.0 {
+        Err(CalcError::NotPositive(name, value))
+    } else {
+        Ok(())
+    }
+}
+
+fn validate_tolerance(name: &'static str, tolerance: f64) -> Result<(), CalcError> {
+    if !tolerance.is_finite() {
+        Err(CalcError::NonFinite(name))
+    } else if !(0.0..1.0).contains(&tolerance) {
+        Err(CalcError::InvalidTolerance(name, tolerance))
+    } else {
+        Ok(())
+    }
 }
 
 fn value_to_tolerance(value: Arg) -> (f64, f64, f64) {
@@ -111,6 +251,11 @@ fn value_to_tolerance(value: Arg) -> (f64, f64, f64) {
             target + (target * tolerance),
         ),
         ArgWithSeries(_, min, max) => (min, min, max),
+        ArgWithBandwidth(_, target, tolerance) => (
+            target,
+            target - (target * tolerance),
+            target + (target * tolerance),
+        ),
     }
 }
 
@@ -121,49 +266,165 @@ pub fn calculate(
     r2: Arg,
     c1: Arg,
     c2: Arg,
-) -> Vec<Selection> {
+) -> Result<Vec<Selection>, CalcError> {
+    calculate_with_diagnostics(frequency, q_factor, r1, r2, c1, c2).map(|(results, _)| results)
+}
+
+pub fn calculate_with_diagnostics(
+    frequency: Arg,
+    q_factor: Arg,
+    r1: Arg,
+    r2: Arg,
+    c1: Arg,
+    c2: Arg,
+) -> Result<(Vec<Selection>, Diagnostics), CalcError> {
+    calculate_chunk(frequency, q_factor, r1, r2, c1, c2, 0, 1)
+        .map(|found| merge_chunks(vec![found]))
+}
+
+pub fn analyze(r1: f64, r2: f64, c1: f64, c2: f64) -> Result<Selection, CalcError> {
+    validate_value("r1", r1)?;
+    validate_value("r2", r2)?;
+    validate_value("c1", c1)?;
+    validate_value("c2", c2)?;
+
+    Ok(Selection::new(r1, r2, c1, c2))
+}
+
+pub fn validate(
+    frequency: &Arg,
+    q_factor: &Arg,
+    r1: &Arg,
+    r2: &Arg,
+    c1: &Arg,
+    c2: &Arg,
+) -> Result<(), CalcError> {
+    frequency.validate("frequency")?;
+    q_factor.validate("q factor")?;
+
+    let (frequency_target, _, _) = value_to_tolerance(frequency.clone());
+
+    q_factor
+        .to_q_factor(frequency_target)
+        .validate("q factor")?;
+    r1.to_checked_table("r1")?;
+    r2.to_checked_table("r2")?;
+    c1.to_checked_table("c1")?;
+    c2.to_checked_table("c2")?;
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn calculate_chunk(
+    frequency: Arg,
+    q_factor: Arg,
+    r1: Arg,
+    r2: Arg,
+    c1: Arg,
+    c2: Arg,
+    chunk: usize,
+    chunks: usize,
+) -> Result<(Vec<Selection>, Diagnostics), CalcError> {
+    frequency.validate("frequency")?;
+    q_factor.validate("q factor")?;
+
     let (frequency_target, frequency_minimum, frequency_maximum) = value_to_tolerance(frequency);
-    let (_q_factor_target, q_factor_minimum, q_factor_maximum) = value_to_tolerance(q_factor);
+    let q_factor = q_factor.to_q_factor(frequency_target);
+
+    q_factor.validate("q factor")?;
+
+    let (q_factor_target, q_factor_minimum, q_factor_maximum) = value_to_tolerance(q_factor);
 
-    let r1_table = r1.to_table();
-    let r2_table = r2.to_table();
-    let c1_table = c1.to_table();
-    let c2_table = c2.to_table();
+    let r1_table = r1.to_checked_table("r1")?;
+    let r2_table = r2.to_checked_table("r2")?;
+    let c1_table = c1.to_checked_table("c1")?;
+    let c2_table = c2.to_checked_table("c2")?;
     let mut results = Vec::new();
+    let mut diagnostics = Diagnostics {
+        table_sizes: [
+            r1_table.len(),
+            r2_table.len(),
+            c1_table.len(),
+            c2_table.len(),
+        ],
+        ..Default::default()
+    };
 
-    for r1_value in &r1_table {
+    for (r1_index, r1_value) in r1_table.iter().enumerate() {
         let inductance_minimum = (r1_value / 10.0) / frequency_target;
         let inductance_maximum = (r1_value * 10.0) / frequency_target;
 
-        for r2_value in &r2_table {
+        for (r2_index, r2_value) in r2_table.iter().enumerate() {
+            if (r1_index * r2_table.len() + r2_index) % chunks.max(1) != chunk {
+                continue;
+            }
+
             for c2_value in &c2_table {
                 let inductance = r1_value * r2_value * c2_value;
 
+                diagnostics.combinations += 1;
+
                 if inductance >= inductance_minimum && inductance <= inductance_maximum {
                     for c1_value in &c1_table {
-                        let frequency = 1.0 / (2.0 * PI * (inductance * c1_value).sqrt());
-                        let q_factor = 2.0 * PI * frequency * inductance / r1_value;
-
-                        if frequency >= frequency_minimum
-                            && frequency <= frequency_maximum
-                            && q_factor >= q_factor_minimum
-                            && q_factor <= q_factor_maximum
-                            && !results.contains(&Selection(
-                                frequency, q_factor, inductance, *r1_value, *r2_value, *c1_value,
-                                *c2_value,
-                            ))
-                        {
-                            results.push(Selection(
-                                frequency, q_factor, inductance, *r1_value, *r2_value, *c1_value,
-                                *c2_value,
-                            ));
+                        let selection = Selection::new(*r1_value, *r2_value, *c1_value, *c2_value);
+                        let frequency = selection.frequency();
+                        let q_factor = selection.q_factor();
+                        let frequency_distance = outside(
+                            frequency,
+                            frequency_minimum,
+                            frequency_maximum,
+                            frequency_target,
+                        );
+                        let q_factor_distance = outside(
+                            q_factor,
+                            q_factor_minimum,
+                            q_factor_maximum,
+                            q_factor_target,
+                        );
+
+                        diagnostics.evaluated += 1;
+
+                        if frequency_distance > 0.0 {
+                            diagnostics.frequency_rejections += 1;
+                        } else if q_factor_distance > 0.0 {
+                            diagnostics.q_factor_rejections += 1;
+                        }
+
+                        match (frequency_distance > 0.0, q_factor_distance > 0.0) {
+                            (true, true) => diagnostics.miss(
+                                selection,
+                                MissedBoth,
+                                frequency_distance + q_factor_distance,
+                            ),
+                            (true, false) => {
+                                diagnostics.miss(selection, MissedFrequency, frequency_distance)
+                            }
+                            (false, true) => {
+                                diagnostics.miss(selection, MissedQFactor, q_factor_distance)
+                            }
+                            (false, false) => results.push(selection),
                         }
                     }
+                } else {
+                    diagnostics.inductance_rejections += 1;
                 }
             }
         }
     }
 
+    Ok((results, diagnostics))
+}
+
+pub fn merge_chunks(chunks: Vec<(Vec<Selection>, Diagnostics)>) -> (Vec<Selection>, Diagnostics) {
+    let mut results = Vec::new();
+    let mut diagnostics = Diagnostics::default();
+
+    for (found, found_diagnostics) in chunks {
+        results.extend(found);
+        diagnostics.merge(found_diagnostics);
+    }
+
     results.sort_by(|a, b| {
         if a.frequency() == b.frequency() {
             if a.q_factor() == b.q_factor() {
@@ -171,26 +432,53 @@ pub fn calculate(
                     if a.r1_resistance() == b.r1_resistance() {
                         if a.r2_resistance() == b.r2_resistance() {
                             if a.c1_capacitance() == b.c1_capacitance() {
-                                a.c2_capacitance().partial_cmp(&b.c2_capacitance()).unwrap()
+                                a.c2_capacitance().total_cmp(&b.c2_capacitance())
                             } else {
-                                a.c1_capacitance().partial_cmp(&b.c1_capacitance()).unwrap()
+                                a.c1_capacitance().total_cmp(&b.c1_capacitance())
                             }
                         } else {
-                            a.r2_resistance().partial_cmp(&b.r2_resistance()).unwrap()
+                            a.r2_resistance().total_cmp(&b.r2_resistance())
                         }
                     } else {
-                        a.r1_resistance().partial_cmp(&b.r1_resistance()).unwrap()
+                        a.r1_resistance().total_cmp(&b.r1_resistance())
                     }
                 } else {
-                    a.inductance().partial_cmp(&b.inductance()).unwrap()
+                    a.inductance().total_cmp(&b.inductance())
                 }
             } else {
-                a.q_factor().partial_cmp(&b.q_factor()).unwrap()
+                a.q_factor().total_cmp(&b.q_factor())
             }
         } else {
-            a.frequency().partial_cmp(&b.frequency()).unwrap()
+            a.frequency().total_cmp(&b.frequency())
         }
     });
+    results.dedup();
+
+    diagnostics.accepted = results.len();
+
+    (results, diagnostics)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    results
+    #[test]
+    fn retarget_keeps_relative_window() {
+        assert_eq!(ArgWithExact(100.0).retarget(200.0), ArgWithExact(200.0));
+        assert_eq!(
+            ArgWithTolerance(100.0, 0.05).retarget(200.0),
+            ArgWithTolerance(200.0, 0.05)
+        );
+        assert_eq!(
+            ArgWithSeries(Series::E12, 100.0, 1000.0).retarget(200.0),
+            ArgWithSeries(Series::E12, 200.0, 2000.0)
+        );
+        assert_eq!(
+            ArgWithSeries(Series::E12, 100.0, 1000.0)
+                .retarget(200.0)
+                .target(),
+            200.0
+        );
+    }
 }
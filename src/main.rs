@@ -1,15 +1,61 @@
+use gloo_worker::{Spawnable, WorkerBridge};
+use gyrator_calculator::bands::*;
+use gyrator_calculator::bandwidth::*;
+use gyrator_calculator::bom::*;
+use gyrator_calculator::diagnostics::*;
+use gyrator_calculator::eq::*;
+use gyrator_calculator::iso::*;
+use gyrator_calculator::ladder::*;
+use gyrator_calculator::matching::*;
+use gyrator_calculator::mna::*;
+use gyrator_calculator::monte_carlo::*;
+use gyrator_calculator::relax::*;
+use gyrator_calculator::response::*;
+use gyrator_calculator::rework::*;
+use gyrator_calculator::sensitivity::*;
 use gyrator_calculator::series::*;
+use gyrator_calculator::spice::*;
+use gyrator_calculator::sweep::*;
+use gyrator_calculator::taper::*;
+use gyrator_calculator::targets::*;
+use gyrator_calculator::tempco::*;
+use gyrator_calculator::tolerance::*;
+use gyrator_calculator::topology::*;
+use gyrator_calculator::tracking::*;
 use gyrator_calculator::units::*;
+use gyrator_calculator::worker::*;
 use gyrator_calculator::*;
+use std::cell::RefCell;
+use std::rc::Rc;
 use wasm_bindgen::JsCast;
 use web_sys::{EventTarget, HtmlInputElement};
 use yew::prelude::*;
 
+mod views;
+
+use views::bands::*;
+use views::eq::*;
+use views::ladder::*;
+use views::matching::*;
+use views::mna::*;
+use views::monte_carlo::*;
+use views::response::*;
+use views::rework::*;
+use views::search::*;
+use views::sensitivity::*;
+use views::sweep::*;
+use views::targets::*;
+use views::tempco::*;
+use views::tolerance::*;
+use views::tracking::*;
+use views::*;
+
 #[derive(Properties, PartialEq)]
 struct InputProps {
     id: &'static str,
     name: &'static str,
     note: Option<&'static str>,
+    preset: Option<UseStateHandle<Preset>>,
     value: UseStateHandle<Input>,
 }
 
@@ -19,11 +65,28 @@ fn input(
         id,
         name,
         note,
+        preset,
         value,
     }: &InputProps,
 ) -> Html {
     let value = value.clone();
 
+    let on_preset_change = {
+        let state = preset.clone();
+
+        move |event: Event| {
+            let element: EventTarget = event
+                .target()
+                .expect("Event should have a target when dispatched");
+
+            if let Some(state) = &state {
+                state.set(Preset::from(
+                    element.unchecked_into::<HtmlInputElement>().value(),
+                ));
+            }
+        }
+    };
+
     let on_series_change = {
         let state = value.clone();
 
@@ -127,6 +190,22 @@ fn input(
                         *fallback,
                     ));
                 }
+            } else if let InputWithBandwidth(measure, _, tolerance, fallback) = &*state {
+                if value.trim().is_empty() {
+                    state.set(InputWithBandwidth(
+                        measure.clone(),
+                        None,
+                        *tolerance,
+                        *fallback,
+                    ));
+                } else {
+                    state.set(InputWithBandwidth(
+                        measure.clone(),
+                        Some(parse_units(value)),
+                        *tolerance,
+                        *fallback,
+                    ));
+                }
             }
         }
     };
@@ -139,10 +218,33 @@ fn input(
                 .target()
                 .expect("Event should have a target when dispatched");
 
-            if let InputWithTolerance(target, _, fallback) = &*state {
-                let value = &element.unchecked_into::<HtmlInputElement>().value();
+            let value = &element.unchecked_into::<HtmlInputElement>().value();
 
+            if let InputWithTolerance(target, _, fallback) = &*state {
                 state.set(InputWithTolerance(*target, parse_units(value), *fallback));
+            } else if let InputWithBandwidth(measure, target, _, fallback) = &*state {
+                state.set(InputWithBandwidth(
+                    measure.clone(),
+                    *target,
+                    parse_units(value),
+                    *fallback,
+                ));
+            }
+        }
+    };
+
+    let on_measure_change = {
+        let state = value.clone();
+
+        move |event: Event| {
+            let element: EventTarget = event
+                .target()
+                .expect("Event should have a target when dispatched");
+
+            if let InputWithBandwidth(_, _, tolerance, fallback) = &*state {
+                let measure = QMeasure::from(element.unchecked_into::<HtmlInputElement>().value());
+
+                state.set(InputWithBandwidth(measure, None, *tolerance, *fallback));
             }
         }
     };
@@ -165,6 +267,21 @@ fn input(
         }
     };
 
+    let format_tolerance_options = |tolerance: &f64| {
+        TOLERANCE_OPTIONS
+            .iter()
+            .map(|item_tolerance| {
+                html! {
+                    <option
+                        selected={tolerance == item_tolerance}
+                        value={format_units(*item_tolerance)}>
+                        {format!("±{}%", item_tolerance * 100.0)}
+                    </option>
+                }
+            })
+            .collect::<Html>()
+    };
+
     match &*value {
         InputWithSeries(series, min, max, min_fallback, max_fallback) => html! {
             <>
@@ -222,13 +339,41 @@ fn input(
                     onchange={on_target_change}
                 />
                 <select id={format!("{}-tolerance", id)} onchange={on_tolerance_change}>
+                    {format_tolerance_options(tolerance)}
+                </select>
+                {
+                    if let Some(preset) = preset {
+                        html! {
+                            <select id={format_id(id, "preset")} onchange={on_preset_change}>
+                                {
+                                    PRESET_OPTIONS.iter().map(|item_preset| html! {
+                                        <option selected={**preset == *item_preset}>{item_preset.as_str()}</option>
+                                    }).collect::<Html>()
+                                }
+                            </select>
+                        }
+                    } else {
+                        html! {}
+                    }
+                }
+            </div>
+        },
+        InputWithBandwidth(measure, target, tolerance, fallback) => html! {
+            <div class="field">
+                <label for={format_id(id, "target")}>{format!("{} target", name)}</label>
+                <input
+                    id={format_id(id, "target")}
+                    placeholder={format!("q {}", format_units(*fallback))}
+                    value={format_value(target)}
+                    onchange={on_target_change}
+                />
+                <select id={format!("{}-tolerance", id)} onchange={on_tolerance_change}>
+                    {format_tolerance_options(tolerance)}
+                </select>
+                <select id={format_id(id, "measure")} onchange={on_measure_change}>
                     {
-                        TOLERANCE_OPTIONS.iter().map(|item_tolerance| html! {
-                            <option
-                                selected={tolerance == item_tolerance}
-                                value={format_units(*item_tolerance)}>
-                                {format!("±{}%", item_tolerance * 100.0)}
-                            </option>
+                        MEASURE_OPTIONS.iter().map(|item_measure| html! {
+                            <option selected={measure == item_measure}>{item_measure.as_str()}</option>
                         }).collect::<Html>()
                     }
                 </select>
@@ -237,15 +382,125 @@ fn input(
     }
 }
 
+#[derive(Clone, PartialEq)]
+enum Mode {
+    Single,
+    Bands,
+    Analyze,
+    Rework,
+    Ladder,
+    Sweep,
+    Match,
+}
+
+impl Mode {
+    fn as_str(&self) -> String {
+        match self {
+            Mode::Single => "single filter".into(),
+            Mode::Bands => "graphic eq".into(),
+            Mode::Analyze => "analyze".into(),
+            Mode::Rework => "rework".into(),
+            Mode::Ladder => "ladder filter".into(),
+            Mode::Sweep => "swept filter".into(),
+            Mode::Match => "matched pair".into(),
+        }
+    }
+}
+
+impl From<String> for Mode {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "graphic eq" => Mode::Bands,
+            "analyze" => Mode::Analyze,
+            "rework" => Mode::Rework,
+            "ladder filter" => Mode::Ladder,
+            "swept filter" => Mode::Sweep,
+            "matched pair" => Mode::Match,
+            _ => Mode::Single,
+        }
+    }
+}
+
+const MODE_OPTIONS: [Mode; 7] = [
+    Mode::Single,
+    Mode::Bands,
+    Mode::Analyze,
+    Mode::Rework,
+    Mode::Ladder,
+    Mode::Sweep,
+    Mode::Match,
+];
+
+#[derive(Properties, PartialEq)]
+struct ModeProps {
+    value: UseStateHandle<Mode>,
+}
+
+#[function_component(ModeField)]
+fn mode_field(ModeProps { value }: &ModeProps) -> Html {
+    let on_mode_change = {
+        let state = value.clone();
+
+        move |event: Event| {
+            let element: EventTarget = event
+                .target()
+                .expect("Event should have a target when dispatched");
+
+            state.set(Mode::from(
+                element.unchecked_into::<HtmlInputElement>().value(),
+            ));
+        }
+    };
+
+    html! {
+        <div class="field">
+            <label for="mode">{"mode"}</label>
+            <select id="mode" onchange={on_mode_change}>
+                {
+                    MODE_OPTIONS.iter().map(|item_mode| html! {
+                        <option selected={**value == *item_mode}>{item_mode.as_str()}</option>
+                    }).collect::<Html>()
+                }
+            </select>
+        </div>
+    }
+}
+
+fn format_download(contents: &str) -> String {
+    let encoded = contents
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{:02X}", byte),
+        })
+        .collect::<String>();
+
+    format!("data:application/octet-stream;charset=utf-8,{}", encoded)
+}
+
+fn format_bandwidth(selection: &Selection) -> String {
+    format!(
+        "{}hz / {}oct",
+        format_units(selection.bandwidth()),
+        format!("{:.2}", selection.bandwidth_octaves())
+            .trim_end_matches('0')
+            .trim_end_matches('.')
+    )
+}
+
 #[derive(Clone, PartialEq)]
 enum SortBy {
     Frequency,
     QFactor,
+    Bandwidth,
     Inductance,
     R1Resistance,
     R2Resistance,
     C1Capacitance,
     C2Capacitance,
+    Sensitivity,
 }
 
 #[derive(PartialEq)]
@@ -301,12 +556,314 @@ fn results_column(
 
 #[derive(Properties, PartialEq)]
 struct ResultsProps {
-    results: UseStateHandle<Option<Vec<Selection>>>,
+    results: UseStateHandle<Option<Result<Vec<Selection>, CalcError>>>,
+    diagnostics: Option<Diagnostics>,
+    #[prop_or_default]
+    relaxations: Vec<Relaxation>,
+    #[prop_or_default]
+    target: Option<(f64, f64)>,
+    #[prop_or_default]
+    pot: Option<f64>,
+}
+
+#[derive(Properties, PartialEq)]
+struct FunnelProps {
+    diagnostics: Diagnostics,
+}
+
+#[function_component(Funnel)]
+fn funnel(FunnelProps { diagnostics }: &FunnelProps) -> Html {
+    let [r1_size, r2_size, c1_size, c2_size] = diagnostics.table_sizes();
+    let stages = [
+        (
+            format!(
+                "r1, r2 and c2 combinations ({} × {} × {})",
+                r1_size, r2_size, c2_size
+            ),
+            diagnostics.combinations(),
+        ),
+        (
+            "rejected by inductance window".into(),
+            diagnostics.inductance_rejections(),
+        ),
+        (
+            format!("evaluated with {} c1 values", c1_size),
+            diagnostics.evaluated(),
+        ),
+        (
+            "rejected by frequency".into(),
+            diagnostics.frequency_rejections(),
+        ),
+        (
+            "rejected by q factor".into(),
+            diagnostics.q_factor_rejections(),
+        ),
+        (
+            "rejected by bandwidth".into(),
+            diagnostics.bandwidth_rejections(),
+        ),
+        ("accepted".into(), diagnostics.accepted()),
+    ];
+
+    html! {
+        <>
+        <table>
+            <thead>
+                <tr>
+                    <th class="stage">{"stage"}</th>
+                    <th class="count">{"candidates"}</th>
+                </tr>
+            </thead>
+            <tbody>
+                {
+                    stages.iter().map(|(stage, count)| {
+                        html!{<tr>
+                            <td class="stage">{stage}</td>
+                            <td class="count">{count}</td>
+                        </tr>}
+                    }).collect::<Html>()
+                }
+            </tbody>
+        </table>
+        {
+            if diagnostics.near_misses().is_empty() {
+                html! {}
+            } else {
+                html! {
+                    <>
+                    <h2>{"nearest misses"}</h2>
+                    <table>
+                        <thead>
+                            <tr>
+                                <th class="frequency">{"frequency"}</th>
+                                <th class="q-factor">{"q factor"}</th>
+                                <th class="r1-resistance">{"r1"}</th>
+                                <th class="r2-resistance">{"r2"}</th>
+                                <th class="c1-capacitance">{"c1"}</th>
+                                <th class="c2-capacitance">{"c2"}</th>
+                                <th class="violation">{"missed"}</th>
+                            </tr>
+                        </thead>
+                        <tbody>
+                            {
+                                diagnostics.near_misses().iter().map(|near_miss| {
+                                    let result = near_miss.selection();
+
+                                    html!{<tr>
+                                        <td class="frequency">{format_units(result.frequency())}</td>
+                                        <td class="q-factor">{format_units(result.q_factor())}</td>
+                                        <td class="r1-resistance">{format_units(result.r1_resistance())}</td>
+                                        <td class="r2-resistance">{format_units(result.r2_resistance())}</td>
+                                        <td class="c1-capacitance">{format_units(result.c1_capacitance())}</td>
+                                        <td class="c2-capacitance">{format_units(result.c2_capacitance())}</td>
+                                        <td class="violation">{near_miss.violation().as_str()}</td>
+                                    </tr>}
+                                }).collect::<Html>()
+                            }
+                        </tbody>
+                    </table>
+                    </>
+                }
+            }
+        }
+        </>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct ResistorKindProps {
+    id: &'static str,
+    name: &'static str,
+    value: UseStateHandle<ResistorKind>,
+}
+
+#[function_component(ResistorKindField)]
+fn resistor_kind_field(ResistorKindProps { id, name, value }: &ResistorKindProps) -> Html {
+    let on_change = {
+        let value = value.clone();
+
+        Callback::from(move |event: Event| {
+            let element: EventTarget = event
+                .target()
+                .expect("Event should have a target when dispatched");
+            let input = element.unchecked_into::<HtmlInputElement>();
+
+            value.set(ResistorKind::from(input.value()));
+        })
+    };
+
+    html! {
+        <div class="field">
+            <label for={*id}>{name}</label>
+            <select id={*id} onchange={on_change}>
+                {
+                    RESISTOR_OPTIONS.iter().map(|item_kind| html! {
+                        <option selected={**value == *item_kind}>{item_kind.as_str()}</option>
+                    }).collect::<Html>()
+                }
+            </select>
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct CapacitorKindProps {
+    id: &'static str,
+    name: &'static str,
+    value: UseStateHandle<CapacitorKind>,
+}
+
+#[function_component(CapacitorKindField)]
+fn capacitor_kind_field(CapacitorKindProps { id, name, value }: &CapacitorKindProps) -> Html {
+    let on_change = {
+        let value = value.clone();
+
+        Callback::from(move |event: Event| {
+            let element: EventTarget = event
+                .target()
+                .expect("Event should have a target when dispatched");
+            let input = element.unchecked_into::<HtmlInputElement>();
+
+            value.set(CapacitorKind::from(input.value()));
+        })
+    };
+
+    html! {
+        <div class="field">
+            <label for={*id}>{name}</label>
+            <select id={*id} onchange={on_change}>
+                {
+                    CAPACITOR_OPTIONS.iter().map(|item_kind| html! {
+                        <option selected={**value == *item_kind}>{item_kind.as_str()}</option>
+                    }).collect::<Html>()
+                }
+            </select>
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct DetailProps {
+    selection: Selection,
+    topology: UseStateHandle<Topology>,
+    #[prop_or_default]
+    target: Option<(f64, f64)>,
+}
+
+#[function_component(Detail)]
+fn detail(
+    DetailProps {
+        selection,
+        topology,
+        target,
+    }: &DetailProps,
+) -> Html {
+    let defaults = Tolerances::default();
+    let r1_tolerance = use_state(|| defaults.r1());
+    let r2_tolerance = use_state(|| defaults.r2());
+    let c1_tolerance = use_state(|| defaults.c1());
+    let c2_tolerance = use_state(|| defaults.c2());
+    let tolerances = Tolerances::new(*r1_tolerance, *r2_tolerance, *c1_tolerance, *c2_tolerance);
+
+    let format_range = |(min, max): (f64, f64), nominal: f64| {
+        format!(
+            "{} to {} ({:.1}% / +{:.1}%)",
+            format_units(min),
+            format_units(max),
+            (min - nominal) / nominal * 100.0,
+            (max - nominal) / nominal * 100.0
+        )
+    };
+
+    let worst = match worst_case(selection, &tolerances) {
+        Ok(worst) => {
+            let corners = [
+                ("lowest frequency", worst.lowest_frequency()),
+                ("highest frequency", worst.highest_frequency()),
+                ("lowest q factor", worst.lowest_q_factor()),
+                ("highest q factor", worst.highest_q_factor()),
+            ];
+
+            html! {
+                <>
+                <p>{format!("frequency {}", format_range(worst.frequency_range(), selection.frequency()))}</p>
+                <p>{format!("q factor {}", format_range(worst.q_factor_range(), selection.q_factor()))}</p>
+                <table>
+                    <thead>
+                        <tr>
+                            <th class="corner">{"corner"}</th>
+                            <th class="frequency">{"frequency"}</th>
+                            <th class="q-factor">{"q factor"}</th>
+                            <th class="r1-resistance">{"r1"}</th>
+                            <th class="r2-resistance">{"r2"}</th>
+                            <th class="c1-capacitance">{"c1"}</th>
+                            <th class="c2-capacitance">{"c2"}</th>
+                        </tr>
+                    </thead>
+                    <tbody>
+                        {
+                            corners.iter().map(|(corner, result)| {
+                                html!{<tr>
+                                    <td class="corner">{corner}</td>
+                                    <td class="frequency">{format_units(result.frequency())}</td>
+                                    <td class="q-factor">{format_units(result.q_factor())}</td>
+                                    <td class="r1-resistance">{format_units(result.r1_resistance())}</td>
+                                    <td class="r2-resistance">{format_units(result.r2_resistance())}</td>
+                                    <td class="c1-capacitance">{format_units(result.c1_capacitance())}</td>
+                                    <td class="c2-capacitance">{format_units(result.c2_capacitance())}</td>
+                                </tr>}
+                            }).collect::<Html>()
+                        }
+                    </tbody>
+                </table>
+                </>
+            }
+        }
+        Err(error) => html! {<p class="error">{error.to_string()}</p>},
+    };
+
+    html! {
+        <div class="detail">
+            <h2>{format!(
+                "worst case for r1 {}, r2 {}, c1 {}, c2 {}",
+                format_units(selection.r1_resistance()),
+                format_units(selection.r2_resistance()),
+                format_units(selection.c1_capacitance()),
+                format_units(selection.c2_capacitance())
+            )}</h2>
+            <div class="fieldset">
+                <ToleranceField id="r1-tolerance" name="r1 tolerance" value={r1_tolerance} />
+                <ToleranceField id="r2-tolerance" name="r2 tolerance" value={r2_tolerance} />
+                <ToleranceField id="c1-tolerance" name="c1 tolerance" value={c1_tolerance} />
+                <ToleranceField id="c2-tolerance" name="c2 tolerance" value={c2_tolerance} />
+            </div>
+            {worst}
+            <SensitivityView selection={selection.clone()} topology={topology.clone()} />
+            <VerifyView selection={selection.clone()} />
+            <YieldView
+                selection={selection.clone()}
+                target={target.unwrap_or((selection.frequency(), selection.q_factor()))}
+                {tolerances} />
+            <TempcoView selection={selection.clone()} />
+        </div>
+    }
 }
 
 #[function_component(Results)]
-fn results(ResultsProps { results }: &ResultsProps) -> Html {
-    let results = results.clone();
+fn results(
+    ResultsProps {
+        results,
+        diagnostics,
+        relaxations,
+        target,
+        pot,
+    }: &ResultsProps,
+) -> Html {
+    let selected = use_state(|| None::<Selection>);
+    let overlay = use_state(Vec::<Selection>::new);
+    let overlay_mode = use_state(|| false);
+    let opamp = use_state(|| IdealOpAmp);
+    let topology = use_state(|| GyratorNetwork);
     let sort_by = use_state(|| SortBy::Frequency);
     let sort_order = use_state(|| SortOrder::Ascending);
 
@@ -327,6 +884,29 @@ fn results(ResultsProps { results }: &ResultsProps) -> Html {
         })
     };
 
+    let on_opamp_change = {
+        let opamp = opamp.clone();
+
+        Callback::from(move |event: Event| {
+            let element: EventTarget = event
+                .target()
+                .expect("Event should have a target when dispatched");
+            let input = element.unchecked_into::<HtmlInputElement>();
+
+            opamp.set(OpAmpModel::from(input.value()));
+        })
+    };
+
+    let on_overlay_change = {
+        let overlay = overlay.clone();
+        let overlay_mode = overlay_mode.clone();
+
+        Callback::from(move |_| {
+            overlay_mode.set(!*overlay_mode);
+            overlay.set(Vec::new());
+        })
+    };
+
     let sort = |a: &Selection, b: &Selection| {
         use SortBy::*;
         use SortOrder::*;
@@ -334,117 +914,291 @@ fn results(ResultsProps { results }: &ResultsProps) -> Html {
         let (a, b) = match *sort_by {
             Frequency => (a.frequency(), b.frequency()),
             QFactor => (a.q_factor(), b.q_factor()),
+            Bandwidth => (a.bandwidth(), b.bandwidth()),
             Inductance => (a.inductance(), b.inductance()),
             R1Resistance => (a.r1_resistance(), b.r1_resistance()),
             R2Resistance => (a.r2_resistance(), b.r2_resistance()),
             C1Capacitance => (a.c1_capacitance(), b.c1_capacitance()),
             C2Capacitance => (a.c2_capacitance(), b.c2_capacitance()),
+            Sensitivity => (
+                sensitivities(a, &topology).total(),
+                sensitivities(b, &topology).total(),
+            ),
         };
 
         match *sort_order {
-            Ascending => a.partial_cmp(&b).unwrap(),
-            Descending => b.partial_cmp(&a).unwrap(),
+            Ascending => a.total_cmp(&b),
+            Descending => b.total_cmp(&a),
         }
     };
 
-    let results = if let Some(mut results) = (*results).clone() {
-        results.sort_by(sort);
-
-        Some(results)
-    } else {
-        None
+    let Some(Ok(results)) = &**results else {
+        return html! {<Outcome<Vec<Selection>> results={results.clone()} />};
     };
+    let mut results = results.clone();
+
+    results.sort_by(sort);
+
+    let relaxations = relaxations
+        .iter()
+        .map(|relaxation| html! {<p class="relaxation">{relaxation.as_str()}</p>})
+        .collect::<Html>();
 
-    if let Some(results) = results {
-        if results.is_empty() {
-            html! {<p>{"no results found"}</p>}
+    let plotted = |results: &[Selection]| {
+        let plotted = if *overlay_mode {
+            overlay
+                .iter()
+                .filter(|selection| results.contains(selection))
+                .cloned()
+                .collect::<Vec<_>>()
         } else {
-            html! {
-                <>
-                <h2>{format!("{} results", results.len())}</h2>
-                <table>
-                    <thead>
-                        <tr>
-                            <ResultsColumn
-                                class="frequency"
-                                name="frequency"
-                                column={SortBy::Frequency}
-                                sort_by={sort_by.clone()}
-                                sort_order={sort_order.clone()}
-                                on_sort={set_sort.clone()} />
-                            <ResultsColumn
-                                class="q-factor"
-                                name="q factor"
-                                column={SortBy::QFactor}
-                                sort_by={sort_by.clone()}
-                                sort_order={sort_order.clone()}
-                                on_sort={set_sort.clone()} />
-                            <ResultsColumn
-                                class="inductance"
-                                name="inductance"
-                                column={SortBy::Inductance}
-                                sort_by={sort_by.clone()}
-                                sort_order={sort_order.clone()}
-                                on_sort={set_sort.clone()} />
-                            <ResultsColumn
-                                class="r1-resistance"
-                                name="r1"
-                                column={SortBy::R1Resistance}
-                                sort_by={sort_by.clone()}
-                                sort_order={sort_order.clone()}
-                                on_sort={set_sort.clone()} />
-                            <ResultsColumn
-                                class="r2-resistance"
-                                name="r2"
-                                column={SortBy::R2Resistance}
-                                sort_by={sort_by.clone()}
-                                sort_order={sort_order.clone()}
-                                on_sort={set_sort.clone()} />
-                            <ResultsColumn
-                                class="c1-capacitance"
-                                name="c1"
-                                column={SortBy::C1Capacitance}
-                                sort_by={sort_by.clone()}
-                                sort_order={sort_order.clone()}
-                                on_sort={set_sort.clone()} />
-                            <ResultsColumn
-                                class="c2-capacitance"
-                                name="c2"
-                                column={SortBy::C2Capacitance}
-                                sort_by={sort_by.clone()}
-                                sort_order={sort_order.clone()}
-                                on_sort={set_sort.clone()} />
-                        </tr>
-                    </thead>
-                    <tbody>
-                        {
-                            results.iter().map(|result| {
-                                html!{<tr>
-                                    <td class="frequency">{format_units(result.frequency())}</td>
-                                    <td class="q-factor">{format_units(result.q_factor())}</td>
-                                    <td class="inductance">{format_units(result.inductance())}</td>
-                                    <td class="r1-resistance">{format_units(result.r1_resistance())}</td>
-                                    <td class="r2-resistance">{format_units(result.r2_resistance())}</td>
-                                    <td class="c1-capacitance">{format_units(result.c1_capacitance())}</td>
-                                    <td class="c2-capacitance">{format_units(result.c2_capacitance())}</td>
-                                </tr>}
-                            }).collect::<Html>()
-                        }
-                    </tbody>
-                </table>
-                </>
+            selected
+                .iter()
+                .filter(|selection| results.contains(selection))
+                .cloned()
+                .collect::<Vec<_>>()
+        };
+
+        if plotted.is_empty() {
+            html! {}
+        } else {
+            html! {<BodePlot selections={plotted} />}
+        }
+    };
+
+    let bode = plotted(&results);
+
+    if results.is_empty() {
+        html! {
+            <>
+            {relaxations}
+            <p>{"no results found"}</p>
+            {
+                if let Some(diagnostics) = diagnostics {
+                    html! {<Funnel diagnostics={diagnostics.clone()} />}
+                } else {
+                    html! {}
+                }
             }
+            </>
         }
     } else {
         html! {
-            <p>{"click \"calculate\" above to begin"}</p>
+            <>
+            <h2>{format!("{} results", results.len())}</h2>
+            {relaxations}
+            <table>
+                <thead>
+                    <tr>
+                        <ResultsColumn
+                            class="frequency"
+                            name="frequency"
+                            column={SortBy::Frequency}
+                            sort_by={sort_by.clone()}
+                            sort_order={sort_order.clone()}
+                            on_sort={set_sort.clone()} />
+                        <ResultsColumn
+                            class="q-factor"
+                            name="q factor"
+                            column={SortBy::QFactor}
+                            sort_by={sort_by.clone()}
+                            sort_order={sort_order.clone()}
+                            on_sort={set_sort.clone()} />
+                        <ResultsColumn
+                            class="bandwidth"
+                            name="bandwidth"
+                            column={SortBy::Bandwidth}
+                            sort_by={sort_by.clone()}
+                            sort_order={sort_order.clone()}
+                            on_sort={set_sort.clone()} />
+                        <ResultsColumn
+                            class="inductance"
+                            name="inductance"
+                            column={SortBy::Inductance}
+                            sort_by={sort_by.clone()}
+                            sort_order={sort_order.clone()}
+                            on_sort={set_sort.clone()} />
+                        <ResultsColumn
+                            class="r1-resistance"
+                            name="r1"
+                            column={SortBy::R1Resistance}
+                            sort_by={sort_by.clone()}
+                            sort_order={sort_order.clone()}
+                            on_sort={set_sort.clone()} />
+                        <ResultsColumn
+                            class="r2-resistance"
+                            name="r2"
+                            column={SortBy::R2Resistance}
+                            sort_by={sort_by.clone()}
+                            sort_order={sort_order.clone()}
+                            on_sort={set_sort.clone()} />
+                        <ResultsColumn
+                            class="c1-capacitance"
+                            name="c1"
+                            column={SortBy::C1Capacitance}
+                            sort_by={sort_by.clone()}
+                            sort_order={sort_order.clone()}
+                            on_sort={set_sort.clone()} />
+                        <ResultsColumn
+                            class="c2-capacitance"
+                            name="c2"
+                            column={SortBy::C2Capacitance}
+                            sort_by={sort_by.clone()}
+                            sort_order={sort_order.clone()}
+                            on_sort={set_sort.clone()} />
+                        {
+                            if pot.is_some() {
+                                html! {<th class="gain">{"boost / cut"}</th>}
+                            } else {
+                                html! {}
+                            }
+                        }
+                        <ResultsColumn
+                            class="sensitivity"
+                            name="sensitivity"
+                            column={SortBy::Sensitivity}
+                            sort_by={sort_by.clone()}
+                            sort_order={sort_order.clone()}
+                            on_sort={set_sort.clone()} />
+                        <th class="netlist">{"spice"}</th>
+                    </tr>
+                </thead>
+                <tbody>
+                    {
+                        results.iter().map(|result| {
+                            let onclick = {
+                                let selected = selected.clone();
+                                let overlay = overlay.clone();
+                                let overlay_mode = overlay_mode.clone();
+                                let result = result.clone();
+
+                                Callback::from(move |_| {
+                                    if *overlay_mode {
+                                        let mut found = (*overlay).clone();
+
+                                        if let Some(index) = found.iter().position(|item| *item == result) {
+                                            found.remove(index);
+                                        } else {
+                                            found.push(result.clone());
+                                        }
+
+                                        overlay.set(found);
+                                    }
+
+                                    selected.set(Some(result.clone()));
+                                })
+                            };
+                            let on_netlist_click = {
+                                let opamp = opamp.clone();
+                                let result = result.clone();
+
+                                Callback::from(move |event: MouseEvent| {
+                                    event.stop_propagation();
+
+                                    let element: web_sys::Element = event
+                                        .current_target()
+                                        .expect("Event should have a target when dispatched")
+                                        .unchecked_into();
+                                    let href = format_download(&netlist(&result, &opamp, FEED_RESISTANCE));
+
+                                    element.set_attribute("href", &href).ok();
+                                })
+                            };
+                            let class = if *overlay_mode && overlay.contains(result) {
+                                "result overlaid"
+                            } else if selected.as_ref() == Some(result) {
+                                "result selected"
+                            } else {
+                                "result"
+                            };
+
+                            html!{<tr {class} {onclick}>
+                                <td class="frequency">{format_units(result.frequency())}</td>
+                                <td class="q-factor">{format_units(result.q_factor())}</td>
+                                <td class="bandwidth">{format_bandwidth(result)}</td>
+                                <td class="inductance">{format_units(result.inductance())}</td>
+                                <td class="r1-resistance">{format_units(result.r1_resistance())}</td>
+                                <td class="r2-resistance">{format_units(result.r2_resistance())}</td>
+                                <td class="c1-capacitance">{format_units(result.c1_capacitance())}</td>
+                                <td class="c2-capacitance">{format_units(result.c2_capacitance())}</td>
+                                if let Some(pot) = *pot {
+                                    <GainCell selection={result.clone()} {pot} />
+                                }
+                                <td class="sensitivity">{format!("{:.2}", sensitivities(result, &topology).total())}</td>
+                                <td class="netlist">
+                                    <a download={netlist_name(result)} href="#" onclick={on_netlist_click}>
+                                        {"download netlist"}
+                                    </a>
+                                </td>
+                            </tr>}
+                        }).collect::<Html>()
+                    }
+                </tbody>
+            </table>
+            <div class="fieldset">
+                <div class="field">
+                    <label for="overlay">{"overlay"}</label>
+                    <input
+                        id="overlay"
+                        type="checkbox"
+                        checked={*overlay_mode}
+                        onchange={on_overlay_change} />
+                    <p>{"click rows to add or remove them from the bode plot"}</p>
+                </div>
+                <div class="field">
+                    <label for="opamp">{"op-amp model"}</label>
+                    <select id="opamp" onchange={on_opamp_change}>
+                        {
+                            OPAMP_OPTIONS.iter().map(|item_opamp| html! {
+                                <option selected={*opamp == *item_opamp}>{item_opamp.as_str()}</option>
+                            }).collect::<Html>()
+                        }
+                    </select>
+                    <p>{"used by the downloaded netlists, vendor models are included from their .lib file"}</p>
+                </div>
+            </div>
+            {bode}
+            {
+                match &*selected {
+                    Some(selection) if results.contains(selection) => {
+                        html! {<Detail selection={selection.clone()} topology={topology.clone()} target={*target} />}
+                    }
+                    _ => html! {<p>{"click a result to see its worst case tolerances"}</p>},
+                }
+            }
+            </>
         }
     }
 }
 
 #[function_component]
 fn App() -> Html {
+    let mode = use_state(|| Mode::Single);
     let results = use_state(|| None);
+    let diagnostics = use_state(|| None);
+    let relaxations = use_state(Vec::new);
+    let band_results = use_state(|| None);
+    let analyze_results = use_state(|| None);
+    let rework_results = use_state(|| None);
+    let ladder_results = use_state(|| None);
+    let ladder_approximation = use_state(|| Butterworth);
+    let ladder_pass = use_state(|| HighPass);
+    let ladder_realization = use_state(|| SimulatedInductors);
+    let ladder_order = use_state(|| 3);
+    let ladder_ripple = use_state(|| 0.5);
+    let sweep_results = use_state(|| None);
+    let sweep_behaviour = use_state(|| ConstantQ);
+    let sweep_taper = use_state(|| Linear);
+    let taper_points = use_state(String::new);
+    let match_results = use_state(|| None);
+    let measured_r1 = use_state(String::new);
+    let measured_r2 = use_state(String::new);
+    let measured_c1 = use_state(String::new);
+    let measured_c2 = use_state(String::new);
+    let band_count = use_state(|| 10);
+    let band_spacing = use_state(|| Spacing::Octave);
+    let band_optimize = use_state(|| Optimize::Closest);
+    let band_preset = use_state(|| Preset::Custom);
     let capacitance_value = use_state(|| {
         InputWithSeries(
             Series::E6,
@@ -464,35 +1218,315 @@ fn App() -> Html {
         )
     });
     let frequency_value = use_state(|| InputWithTolerance(None, 0.1, 100.0));
-    let q_factor_value = use_state(|| InputWithTolerance(None, 0.2, 4.0));
+    let q_factor_value = use_state(|| InputWithBandwidth(QMeasure::QFactor, None, 0.2, 4.0));
     let r1_value = use_state(|| InputWithExact(None, Some(470.0)));
     let r2_value = use_state(|| InputWithExact(None, None));
     let c1_value = use_state(|| InputWithExact(None, None));
     let c2_value = use_state(|| InputWithExact(None, None));
+    let relax_value = use_state(|| InputWithExact(None, None));
+    let pot_value = use_state(|| InputWithExact(None, Some(DEFAULT_POT)));
+    let impedance_value = use_state(|| InputWithExact(None, Some(10000.0)));
+    let target_pair = use_state(|| FrequencyAndQ);
+    let inductance_value = use_state(|| InputWithTolerance(None, 0.1, 1.0));
+    let bandwidth_value = use_state(|| InputWithTolerance(None, 0.1, 25.0));
+    let test_frequency_value = use_state(|| InputWithExact(None, Some(1000.0)));
+    let test_q_factor_value = use_state(|| InputWithBandwidth(QMeasure::QFactor, None, 0.1, 10.0));
+    let highest_value = use_state(|| InputWithTolerance(None, 0.1, 1000.0));
+    let target = use_state(|| None);
+    let progress = use_state(|| None);
+    let search = use_mut_ref(|| None);
+    let search_id = use_mut_ref(|| 0);
+
+    let oncancel = {
+        let search = search.clone();
+        let progress = progress.clone();
+
+        Callback::from(move |_| {
+            stop_search(&search);
+            progress.set(None);
+        })
+    };
 
     let onclick = {
+        let mode = mode.clone();
         let results = results.clone();
+        let diagnostics = diagnostics.clone();
+        let relaxations = relaxations.clone();
+        let relax_value = relax_value.clone();
+        let band_results = band_results.clone();
+        let analyze_results = analyze_results.clone();
+        let rework_results = rework_results.clone();
+        let ladder_results = ladder_results.clone();
+        let ladder_approximation = ladder_approximation.clone();
+        let ladder_pass = ladder_pass.clone();
+        let ladder_realization = ladder_realization.clone();
+        let ladder_order = ladder_order.clone();
+        let ladder_ripple = ladder_ripple.clone();
+        let impedance_value = impedance_value.clone();
+        let sweep_results = sweep_results.clone();
+        let sweep_behaviour = sweep_behaviour.clone();
+        let sweep_taper = sweep_taper.clone();
+        let taper_points = taper_points.clone();
+        let match_results = match_results.clone();
+        let measured_r1 = measured_r1.clone();
+        let measured_r2 = measured_r2.clone();
+        let measured_c1 = measured_c1.clone();
+        let measured_c2 = measured_c2.clone();
+        let highest_value = highest_value.clone();
+        let band_count = band_count.clone();
+        let band_spacing = band_spacing.clone();
+        let band_optimize = band_optimize.clone();
+        let band_preset = band_preset.clone();
         let capacitance_value = capacitance_value.clone();
         let resistance_value = resistance_value.clone();
         let frequency_value = frequency_value.clone();
         let q_factor_value = q_factor_value.clone();
+        let target_pair = target_pair.clone();
+        let inductance_value = inductance_value.clone();
+        let bandwidth_value = bandwidth_value.clone();
+        let test_frequency_value = test_frequency_value.clone();
+        let test_q_factor_value = test_q_factor_value.clone();
         let r1_value = r1_value.clone();
         let r2_value = r2_value.clone();
         let c1_value = c1_value.clone();
         let c2_value = c2_value.clone();
+        let target = target.clone();
+        let progress = progress.clone();
+        let search = search.clone();
+        let search_id = search_id.clone();
 
         move |_| {
-            let capacitance_value = capacitance_value.to_arg().unwrap();
-            let resistance_value = resistance_value.to_arg().unwrap();
-
-            results.set(Some(calculate(
-                frequency_value.to_arg().unwrap(),
-                q_factor_value.to_arg().unwrap(),
-                r1_value.to_arg().unwrap_or(resistance_value.clone()),
-                r2_value.to_arg().unwrap_or(resistance_value),
-                c1_value.to_arg().unwrap_or(capacitance_value.clone()),
-                c2_value.to_arg().unwrap_or(capacitance_value),
-            )));
+            let args = || -> Result<[Arg; 6], CalcError> {
+                let capacitance_value = capacitance_value
+                    .to_arg()
+                    .ok_or(CalcError::MissingValue("capacitance"))?;
+                let resistance_value = resistance_value
+                    .to_arg()
+                    .ok_or(CalcError::MissingValue("resistance"))?;
+
+                Ok([
+                    frequency_value
+                        .to_arg()
+                        .ok_or(CalcError::MissingValue("frequency"))?,
+                    q_factor_value
+                        .to_arg()
+                        .ok_or(CalcError::MissingValue("q factor"))?,
+                    r1_value.to_arg().unwrap_or(resistance_value.clone()),
+                    r2_value.to_arg().unwrap_or(resistance_value),
+                    c1_value.to_arg().unwrap_or(capacitance_value.clone()),
+                    c2_value.to_arg().unwrap_or(capacitance_value),
+                ])
+            };
+
+            let value = |input: &Input, name: &'static str| match input.to_arg() {
+                Some(ArgWithExact(value)) => Ok(value),
+                _ => Err(CalcError::MissingValue(name)),
+            };
+
+            let quantity_arg = |quantity: &Quantity| -> Result<Arg, CalcError> {
+                let input = match quantity {
+                    Quantity::Frequency => &frequency_value,
+                    Quantity::QFactor => &q_factor_value,
+                    Quantity::TestQFactor => &test_q_factor_value,
+                    Quantity::Inductance => &inductance_value,
+                    Quantity::Bandwidth => &bandwidth_value,
+                };
+
+                input.to_arg().ok_or(CalcError::MissingValue("target"))
+            };
+
+            match *mode {
+                Mode::Single if *target_pair != FrequencyAndQ => {
+                    stop_search(&search);
+                    progress.set(None);
+                    target.set(None);
+                    relaxations.set(Vec::new());
+
+                    let found = args().and_then(|[_, _, r1, r2, c1, c2]| {
+                        let (first, second) = target_pair.quantities();
+
+                        calculate_targets(
+                            &target_pair,
+                            quantity_arg(&first)?,
+                            quantity_arg(&second)?,
+                            if target_pair.uses(&Quantity::TestQFactor) {
+                                value(&test_frequency_value, "test frequency")?
+                            } else {
+                                0.0
+                            },
+                            r1,
+                            r2,
+                            c1,
+                            c2,
+                        )
+                    });
+
+                    match found {
+                        Ok((found, found_diagnostics)) => {
+                            results.set(Some(Ok(found)));
+                            diagnostics.set(Some(found_diagnostics));
+                        }
+                        Err(error) => {
+                            results.set(Some(Err(error)));
+                            diagnostics.set(None);
+                        }
+                    }
+                }
+                Mode::Single => {
+                    stop_search(&search);
+                    progress.set(None);
+                    target.set(args().ok().map(|[frequency, q_factor, ..]| {
+                        let frequency = frequency.target();
+
+                        (frequency, q_factor.to_q_factor(frequency).target())
+                    }));
+
+                    let found = args().and_then(|[frequency, q_factor, r1, r2, c1, c2]| {
+                        validate(&frequency, &q_factor, &r1, &r2, &c1, &c2)?;
+
+                        let minimum = match relax_value.to_arg() {
+                            Some(ArgWithExact(minimum)) => Some(minimum as usize),
+                            _ => None,
+                        };
+                        let id = {
+                            let mut search_id = search_id.borrow_mut();
+
+                            *search_id += 1;
+                            *search_id
+                        };
+
+                        start_search(
+                            search.clone(),
+                            id,
+                            [frequency, q_factor, r1, r2, c1, c2],
+                            minimum,
+                            results.clone(),
+                            diagnostics.clone(),
+                            relaxations.clone(),
+                            progress.clone(),
+                        );
+
+                        Ok(())
+                    });
+
+                    if let Err(error) = found {
+                        results.set(Some(Err(error)));
+                        diagnostics.set(None);
+                        relaxations.set(Vec::new());
+                    }
+                }
+                Mode::Bands => band_results.set(Some(args().and_then(
+                    |[frequency, q_factor, r1, r2, c1, c2]| {
+                        optimize_bands(
+                            *band_count,
+                            (*band_spacing).clone(),
+                            (*band_preset).clone(),
+                            frequency,
+                            q_factor,
+                            r1,
+                            r2,
+                            c1,
+                            c2,
+                            (*band_optimize).clone(),
+                        )
+                    },
+                ))),
+                Mode::Analyze => {
+                    let found = || -> Result<Vec<Selection>, CalcError> {
+                        Ok(vec![analyze(
+                            value(&r1_value, "r1")?,
+                            value(&r2_value, "r2")?,
+                            value(&c1_value, "c1")?,
+                            value(&c2_value, "c2")?,
+                        )?])
+                    };
+
+                    analyze_results.set(Some(found()));
+                }
+                Mode::Rework => {
+                    let found = || -> Result<Vec<Rework>, CalcError> {
+                        rework(
+                            value(&r1_value, "r1")?,
+                            value(&r2_value, "r2")?,
+                            value(&c1_value, "c1")?,
+                            value(&c2_value, "c2")?,
+                            frequency_value
+                                .to_arg()
+                                .ok_or(CalcError::MissingValue("frequency"))?,
+                            q_factor_value
+                                .to_arg()
+                                .ok_or(CalcError::MissingValue("q factor"))?,
+                            resistance_value
+                                .to_arg()
+                                .ok_or(CalcError::MissingValue("resistance"))?,
+                            capacitance_value
+                                .to_arg()
+                                .ok_or(CalcError::MissingValue("capacitance"))?,
+                        )
+                    };
+
+                    rework_results.set(Some(found()));
+                }
+                Mode::Ladder => {
+                    let found = || -> Result<Ladder, CalcError> {
+                        ladder(
+                            &ladder_approximation,
+                            &ladder_pass,
+                            &ladder_realization,
+                            *ladder_order,
+                            *ladder_ripple,
+                            frequency_value
+                                .to_arg()
+                                .ok_or(CalcError::MissingValue("cutoff"))?,
+                            value(&impedance_value, "impedance")?,
+                            resistance_value
+                                .to_arg()
+                                .ok_or(CalcError::MissingValue("resistance"))?,
+                            capacitance_value
+                                .to_arg()
+                                .ok_or(CalcError::MissingValue("capacitance"))?,
+                        )
+                    };
+
+                    ladder_results.set(Some(found()));
+                }
+                Mode::Sweep => {
+                    let found = || -> Result<Vec<Sweep>, CalcError> {
+                        let taper = match &*sweep_taper {
+                            Piecewise(_) => Piecewise(parse_points(&taper_points)?),
+                            taper => taper.clone(),
+                        };
+
+                        sweep(
+                            frequency_value
+                                .to_arg()
+                                .ok_or(CalcError::MissingValue("lowest frequency"))?,
+                            highest_value
+                                .to_arg()
+                                .ok_or(CalcError::MissingValue("highest frequency"))?,
+                            q_factor_value
+                                .to_arg()
+                                .ok_or(CalcError::MissingValue("q factor"))?,
+                            &sweep_behaviour,
+                            &taper,
+                            resistance_value
+                                .to_arg()
+                                .ok_or(CalcError::MissingValue("resistance"))?,
+                            capacitance_value
+                                .to_arg()
+                                .ok_or(CalcError::MissingValue("capacitance"))?,
+                        )
+                    };
+
+                    sweep_results.set(Some(found()));
+                }
+                Mode::Match => match_results.set(Some(match_pairs(
+                    &parse_measured(&measured_r1),
+                    &parse_measured(&measured_r2),
+                    &parse_measured(&measured_c1),
+                    &parse_measured(&measured_c2),
+                ))),
+            }
         }
     };
 
@@ -501,26 +1535,217 @@ fn App() -> Html {
             <h1>{"gyrator calculator"}</h1>
             <p>{"this calculator aids in the design of gyrator based filters by selecting appropriate values for the desired q and frequency"}</p>
 
-            <h2>{"component ranges"}</h2>
             <div class="fieldset">
-                <InputField id="capacitance" name="capacitance" value={capacitance_value} />
-                <InputField id="resistance" name="resistance" value={resistance_value} />
+                <ModeField value={mode.clone()} />
             </div>
 
-            <h2>{"gyrator values"}</h2>
-            <p>{"avoid setting too many optional fields as it will limit the results significantly"}</p>
+            {
+                if *mode == Mode::Match {
+                    html! {
+                        <>
+                        <h2>{"measured parts"}</h2>
+                        <p>{"two channels are built from these parts with the closest matching frequency and q"}</p>
+                        </>
+                    }
+                } else if *mode == Mode::Analyze {
+                    html! {
+                        <>
+                        <h2>{"gyrator values"}</h2>
+                        <p>{"enter the component values already on the board to see what the filter does"}</p>
+                        </>
+                    }
+                } else {
+                    html! {
+                        <>
+                        <h2>{"component ranges"}</h2>
+                        <div class="fieldset">
+                            <InputField id="capacitance" name="capacitance" value={capacitance_value} />
+                            <InputField id="resistance" name="resistance" value={resistance_value} />
+                        </div>
+
+                        <h2>{"gyrator values"}</h2>
+                        {
+                            if *mode == Mode::Rework {
+                                html! {<p>{"enter the values on the board and the new target, up to two parts will be changed"}</p>}
+                            } else if *mode == Mode::Sweep {
+                                html! {<p>{"a standard pot and fixed parts are picked to cover the frequency range"}</p>}
+                            } else if *mode == Mode::Ladder {
+                                html! {<p>{"each inductor is replaced by a gyrator or gic tuned to the cutoff frequency"}</p>}
+                            } else {
+                                html! {<p>{"avoid setting too many optional fields as it will limit the results significantly"}</p>}
+                            }
+                        }
+                        </>
+                    }
+                }
+            }
             <div class="fieldset">
-                <InputField id="frequency" name="frequency" value={frequency_value} />
-                <InputField id="q-factor" name="q factor" value={q_factor_value} />
-                <InputField id="r1" name="r1" note="the value of r1 sets the gain of the gyrator" value={r1_value} />
-                <InputField id="r2" name="r2" note="use a specific r2 value" value={r2_value} />
-                <InputField id="c1" name="c1" note="use a specific c1 value" value={c1_value} />
-                <InputField id="c2" name="c2" note="use a specific c2 value" value={c2_value} />
+                {
+                    if *mode == Mode::Analyze {
+                        html! {}
+                    } else if *mode == Mode::Match {
+                        html! {
+                            <>
+                                <MeasuredField id="measured-r1" name="r1" value={measured_r1} />
+                                <MeasuredField id="measured-r2" name="r2" value={measured_r2} />
+                                <MeasuredField id="measured-c1" name="c1" value={measured_c1} />
+                                <MeasuredField id="measured-c2" name="c2" value={measured_c2} />
+                            </>
+                        }
+                    } else if *mode == Mode::Sweep {
+                        html! {
+                            <>
+                                <BehaviourField value={sweep_behaviour} taper={sweep_taper} points={taper_points} />
+                                <InputField id="frequency" name="lowest frequency" value={frequency_value} />
+                                <InputField id="highest" name="highest frequency" value={highest_value} />
+                                <InputField id="q-factor" name="q factor" note="q at the geometric centre of the sweep" value={q_factor_value} />
+                            </>
+                        }
+                    } else if *mode == Mode::Ladder {
+                        html! {
+                            <>
+                                <LadderField
+                                    approximation={ladder_approximation}
+                                    pass={ladder_pass}
+                                    realization={ladder_realization}
+                                    order={ladder_order}
+                                    ripple={ladder_ripple} />
+                                <InputField id="frequency" name="cutoff" note="the tolerance also applies to each simulated inductor" value={frequency_value} />
+                                <InputField id="impedance" name="impedance" note="source and load resistance of the ladder" value={impedance_value} />
+                            </>
+                        }
+                    } else if *mode == Mode::Bands {
+                        html! {
+                            <>
+                                <BandsField count={band_count} spacing={band_spacing} optimize={band_optimize} />
+                                <InputField id="frequency" name="lowest band" preset={band_preset} value={frequency_value} />
+                                <InputField id="q-factor" name="q factor" value={q_factor_value.clone()} />
+                            </>
+                        }
+                    } else if *mode == Mode::Single {
+                        html! {
+                            <>
+                                <TargetsField value={target_pair.clone()} />
+                                {
+                                    if target_pair.uses(&Quantity::Frequency) {
+                                        html! {<InputField id="frequency" name="frequency" value={frequency_value.clone()} />}
+                                    } else {
+                                        html! {}
+                                    }
+                                }
+                                {
+                                    if target_pair.uses(&Quantity::QFactor) {
+                                        html! {<InputField id="q-factor" name="q factor" value={q_factor_value.clone()} />}
+                                    } else {
+                                        html! {}
+                                    }
+                                }
+                                {
+                                    if target_pair.uses(&Quantity::TestQFactor) {
+                                        html! {
+                                            <>
+                                                <InputField id="test-q-factor" name="q at test frequency" value={test_q_factor_value} />
+                                                <InputField id="test-frequency" name="test frequency" note="frequency at which q = 2πfl/r1 is measured" value={test_frequency_value} />
+                                            </>
+                                        }
+                                    } else {
+                                        html! {}
+                                    }
+                                }
+                                {
+                                    if target_pair.uses(&Quantity::Inductance) {
+                                        html! {<InputField id="inductance" name="inductance" value={inductance_value} />}
+                                    } else {
+                                        html! {}
+                                    }
+                                }
+                                {
+                                    if target_pair.uses(&Quantity::Bandwidth) {
+                                        html! {<InputField id="bandwidth" name="bandwidth" note="bandwidth in hz" value={bandwidth_value} />}
+                                    } else {
+                                        html! {}
+                                    }
+                                }
+                            </>
+                        }
+                    } else {
+                        html! {
+                            <>
+                                <InputField id="frequency" name="frequency" value={frequency_value} />
+                                <InputField id="q-factor" name="q factor" value={q_factor_value} />
+                            </>
+                        }
+                    }
+                }
+                {
+                    if matches!(*mode, Mode::Ladder | Mode::Sweep | Mode::Match) {
+                        html! {}
+                    } else {
+                        html! {
+                            <>
+                                <InputField id="r1" name="r1" note="the value of r1 sets the gain of the gyrator" value={r1_value} />
+                                {
+                                    if matches!(*mode, Mode::Single | Mode::Analyze) {
+                                        html! {<InputField id="pot" name="slider pot" note="boost and cut are set by the pot value against r1" value={pot_value.clone()} />}
+                                    } else {
+                                        html! {}
+                                    }
+                                }
+                                <InputField id="r2" name="r2" note="use a specific r2 value" value={r2_value} />
+                                <InputField id="c1" name="c1" note="use a specific c1 value" value={c1_value} />
+                                <InputField id="c2" name="c2" note="use a specific c2 value" value={c2_value} />
+                            </>
+                        }
+                    }
+                }
+                {
+                    if *mode == Mode::Single && *target_pair == FrequencyAndQ {
+                        html! {
+                            <InputField
+                                id="relax"
+                                name="minimum results"
+                                note="loosen tolerances and series until this many results are found"
+                                value={relax_value} />
+                        }
+                    } else {
+                        html! {}
+                    }
+                }
             </div>
 
-            <button type="button" {onclick}>{"calculate"}</button>
+            <button type="button" {onclick}>{
+                if *mode == Mode::Analyze { "analyze" } else { "calculate" }
+            }</button>
+            <SearchProgress {progress} {oncancel} />
 
-            <Results results={results} />
+            {
+                match *mode {
+                    Mode::Single => html! {
+                        <Results
+                            results={results}
+                            diagnostics={(*diagnostics).clone()}
+                            relaxations={(*relaxations).clone()}
+                            target={*target}
+                            pot={match pot_value.to_arg() {
+                                Some(ArgWithExact(pot)) => Some(pot),
+                                _ => None,
+                            }} />
+                    },
+                    Mode::Bands => html! {<BandResults results={band_results} />},
+                    Mode::Rework => html! {<ReworkResults results={rework_results} />},
+                    Mode::Ladder => html! {<LadderResults results={ladder_results} />},
+                    Mode::Sweep => html! {<SweepResults results={sweep_results} />},
+                    Mode::Match => html! {<MatchResults results={match_results} />},
+                    Mode::Analyze => html! {
+                        <Results
+                            results={analyze_results}
+                            pot={match pot_value.to_arg() {
+                                Some(ArgWithExact(pot)) => Some(pot),
+                                _ => None,
+                            }} />
+                    },
+                }
+            }
         </form>
     }
 }
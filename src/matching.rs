@@ -0,0 +1,152 @@
+use crate::units::*;
+use crate::*;
+
+pub const MIN_MEASURED: usize = 2;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MatchedPair(Selection, Selection);
+
+impl MatchedPair {
+    pub fn left(&self) -> &Selection {
+        &self.0
+    }
+
+    pub fn right(&self) -> &Selection {
+        &self.1
+    }
+
+    pub fn frequency_mismatch(&self) -> f64 {
+        self.1.frequency() / self.0.frequency() - 1.0
+    }
+
+    pub fn q_factor_mismatch(&self) -> f64 {
+        self.1.q_factor() / self.0.q_factor() - 1.0
+    }
+}
+
+pub fn parse_measured(value: &str) -> Vec<f64> {
+    value
+        .split(|character: char| character == ',' || character.is_whitespace())
+        .filter(|part| !part.is_empty())
+        .map(parse_units)
+        .collect()
+}
+
+fn validate_measured(name: &'static str, parts: &[f64]) -> Result<(), CalcError> {
+    if parts.len() < MIN_MEASURED {
+        return Err(CalcError::NotEnoughParts(name, parts.len()));
+    }
+
+    parts
+        .iter()
+        .try_for_each(|part| validate_value(name, *part))
+}
+
+fn ratios(parts: &[f64]) -> Vec<(f64, usize, usize)> {
+    let mut ratios = Vec::new();
+
+    for (left, left_value) in parts.iter().enumerate() {
+        for (right, right_value) in parts.iter().enumerate() {
+            if left != right {
+                ratios.push(((right_value / left_value).ln(), left, right));
+            }
+        }
+    }
+
+    ratios
+}
+
+fn best_pairs(first: &[f64], second: &[f64]) -> ((usize, usize), (usize, usize)) {
+    let first_ratios = ratios(first);
+    let mut second_ratios = ratios(second);
+    let mut best = (f64::INFINITY, (0, 1), (0, 1));
+
+    second_ratios.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    for (ratio, first_left, first_right) in first_ratios {
+        let index = second_ratios.partition_point(|(other, _, _)| *other < -ratio);
+
+        for (other, second_left, second_right) in
+            second_ratios.iter().skip(index.saturating_sub(1)).take(2)
+        {
+            let mismatch = (ratio + other).abs();
+
+            if mismatch < best.0 {
+                best = (
+                    mismatch,
+                    (first_left, first_right),
+                    (*second_left, *second_right),
+                );
+            }
+        }
+    }
+
+    (best.1, best.2)
+}
+
+pub fn match_pairs(
+    r1: &[f64],
+    r2: &[f64],
+    c1: &[f64],
+    c2: &[f64],
+) -> Result<MatchedPair, CalcError> {
+    validate_measured("r1", r1)?;
+    validate_measured("r2", r2)?;
+    validate_measured("c1", c1)?;
+    validate_measured("c2", c2)?;
+
+    let ((r1_left, r1_right), (c1_left, c1_right)) = best_pairs(r1, c1);
+    let ((r2_left, r2_right), (c2_left, c2_right)) = best_pairs(r2, c2);
+
+    Ok(MatchedPair(
+        Selection::new(r1[r1_left], r2[r2_left], c1[c1_left], c2[c2_left]),
+        Selection::new(r1[r1_right], r2[r2_right], c1[c1_right], c2[c2_right]),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matched_bins() -> [Vec<f64>; 4] {
+        [
+            vec![1000.0, 1010.0, 1500.0],
+            vec![10000.0, 9900.0, 12000.0],
+            vec![100e-9, 100e-9 * 1000.0 / 1010.0, 47e-9],
+            vec![10e-9, 10e-9 * 10000.0 / 9900.0, 22e-9],
+        ]
+    }
+
+    #[test]
+    fn best_pairs_matches_products() {
+        let [r1, _, c1, _] = matched_bins();
+        let ((r1_left, r1_right), (c1_left, c1_right)) = best_pairs(&r1, &c1);
+
+        assert_ne!(r1_left, r1_right);
+        assert!((r1[r1_left] * c1[c1_left] / (r1[r1_right] * c1[c1_right]) - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn matching_products_have_no_mismatch() {
+        let [r1, r2, c1, c2] = matched_bins();
+        let matched = match_pairs(&r1, &r2, &c1, &c2).unwrap();
+
+        assert_ne!(matched.left(), matched.right());
+        assert!(matched.frequency_mismatch().abs() < 1e-9);
+        assert!(matched.q_factor_mismatch().abs() < 1e-9);
+    }
+
+    #[test]
+    fn rejects_too_few_parts() {
+        let [r1, r2, c1, c2] = matched_bins();
+
+        assert_eq!(
+            match_pairs(&r1[..1], &r2, &c1, &c2),
+            Err(CalcError::NotEnoughParts("r1", 1))
+        );
+        assert_eq!(
+            match_pairs(&r1, &r2, &c1, &[]),
+            Err(CalcError::NotEnoughParts("c2", 0))
+        );
+    }
+}
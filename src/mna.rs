@@ -0,0 +1,327 @@
+use crate::response::*;
+use crate::topology::*;
+use crate::*;
+pub use Element::*;
+
+pub const OPAMP_GAIN: f64 = 100000.0;
+
+pub const AGREEMENT_TOLERANCE: f64 = 0.01;
+
+const SEARCH_DECADES: f64 = 2.0;
+
+const SEARCH_POINTS: usize = 400;
+
+const REFINE_STEPS: usize = 60;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Element {
+    Resistor(usize, usize, f64),
+    Capacitor(usize, usize, f64),
+    VoltageSource(usize, usize, f64),
+    Vcvs(usize, usize, usize, usize, f64),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Circuit(usize, Vec<Element>);
+
+impl Circuit {
+    pub fn new(nodes: usize) -> Circuit {
+        Circuit(nodes, Vec::new())
+    }
+
+    pub fn add(&mut self, element: Element) {
+        self.1.push(element);
+    }
+
+    pub fn nodes(&self) -> usize {
+        self.0
+    }
+
+    pub fn elements(&self) -> &[Element] {
+        &self.1
+    }
+
+    pub fn solve(&self, frequency: f64) -> Result<Vec<Complex>, CalcError> {
+        let nodes = self.0 - 1;
+        let sources = self
+            .1
+            .iter()
+            .filter(|element| matches!(element, VoltageSource(..) | Vcvs(..)))
+            .count();
+        let size = nodes + sources;
+        let zero = Complex::from(0.0);
+        let mut matrix = vec![vec![zero; size]; size];
+        let mut rhs = vec![zero; size];
+        let mut source = nodes;
+        let s = Complex::new(0.0, 2.0 * PI * frequency);
+
+        for element in &self.1 {
+            match element {
+                Resistor(a, b, resistance) => {
+                    stamp_admittance(&mut matrix, *a, *b, Complex::from(1.0 / resistance));
+                }
+                Capacitor(a, b, capacitance) => {
+                    stamp_admittance(&mut matrix, *a, *b, s * Complex::from(*capacitance));
+                }
+                VoltageSource(p, n, voltage) => {
+                    stamp_source(&mut matrix, source, *p, *n);
+                    rhs[source] = Complex::from(*voltage);
+                    source += 1;
+                }
+                Vcvs(p, n, input_p, input_n, gain) => {
+                    stamp_source(&mut matrix, source, *p, *n);
+
+                    if *input_p > 0 {
+                        matrix[source][input_p - 1] = matrix[source][input_p - 1] - (*gain).into();
+                    }
+
+                    if *input_n > 0 {
+                        matrix[source][input_n - 1] = matrix[source][input_n - 1] + (*gain).into();
+                    }
+
+                    source += 1;
+                }
+            }
+        }
+
+        eliminate(matrix, rhs).ok_or(CalcError::SingularCircuit(frequency))
+    }
+}
+
+fn stamp_admittance(matrix: &mut [Vec<Complex>], a: usize, b: usize, admittance: Complex) {
+    if a > 0 {
+        matrix[a - 1][a - 1] = matrix[a - 1][a - 1] + admittance;
+    }
+
+    if b > 0 {
+        matrix[b - 1][b - 1] = matrix[b - 1][b - 1] + admittance;
+    }
+
+    if a > 0 && b > 0 {
+        matrix[a - 1][b - 1] = matrix[a - 1][b - 1] - admittance;
+        matrix[b - 1][a - 1] = matrix[b - 1][a - 1] - admittance;
+    }
+}
+
+fn stamp_source(matrix: &mut [Vec<Complex>], source: usize, p: usize, n: usize) {
+    let one = Complex::from(1.0);
+
+    if p > 0 {
+        matrix[p - 1][source] = matrix[p - 1][source] + one;
+        matrix[source][p - 1] = matrix[source][p - 1] + one;
+    }
+
+    if n > 0 {
+        matrix[n - 1][source] = matrix[n - 1][source] - one;
+        matrix[source][n - 1] = matrix[source][n - 1] - one;
+    }
+}
+
+fn eliminate(mut matrix: Vec<Vec<Complex>>, mut rhs: Vec<Complex>) -> Option<Vec<Complex>> {
+    let size = rhs.len();
+
+    for column in 0..size {
+        let pivot = (column..size).max_by(|a, b| {
+            matrix[*a][column]
+                .magnitude()
+                .total_cmp(&matrix[*b][column].magnitude())
+        })?;
+
+        if matrix[pivot][column].magnitude() < f64::EPSILON * f64::EPSILON {
+            return None;
+        }
+
+        matrix.swap(column, pivot);
+        rhs.swap(column, pivot);
+
+        let (pivot_rows, rows) = matrix.split_at_mut(column + 1);
+        let pivot_row = &pivot_rows[column];
+
+        for (offset, row) in rows.iter_mut().enumerate() {
+            let factor = row[column] / pivot_row[column];
+
+            for (value, pivot_value) in row.iter_mut().zip(pivot_row).skip(column) {
+                *value = *value - factor * *pivot_value;
+            }
+
+            rhs[column + 1 + offset] = rhs[column + 1 + offset] - factor * rhs[column];
+        }
+    }
+
+    let mut solution = vec![Complex::from(0.0); size];
+
+    for row in (0..size).rev() {
+        let mut sum = rhs[row];
+
+        for index in row + 1..size {
+            sum = sum - matrix[row][index] * solution[index];
+        }
+
+        solution[row] = sum / matrix[row][row];
+    }
+
+    Some(solution)
+}
+
+pub fn gyrator_circuit(selection: &Selection) -> Circuit {
+    let mut circuit = Circuit::new(5);
+
+    circuit.add(VoltageSource(1, 0, 1.0));
+    circuit.add(Capacitor(1, 2, selection.c1_capacitance()));
+    circuit.add(Capacitor(2, 3, selection.c2_capacitance()));
+    circuit.add(Resistor(3, 0, selection.r2_resistance()));
+    circuit.add(Resistor(2, 4, selection.r1_resistance()));
+    circuit.add(Vcvs(4, 0, 3, 4, OPAMP_GAIN));
+    circuit
+}
+
+fn admittance(circuit: &Circuit, frequency: f64) -> Result<f64, CalcError> {
+    let solution = circuit.solve(frequency)?;
+
+    Ok(solution[circuit.nodes() - 1].magnitude())
+}
+
+fn refine<F>(mut low: f64, mut high: f64, mut is_low: F) -> Result<f64, CalcError>
+where
+    F: FnMut(f64) -> Result<bool, CalcError>,
+{
+    for _ in 0..REFINE_STEPS {
+        let middle = (low * high).sqrt();
+
+        if is_low(middle)? {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+
+    Ok((low * high).sqrt())
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Verification(f64, f64, f64, Option<f64>);
+
+impl Verification {
+    pub fn formula_frequency(&self) -> f64 {
+        self.0
+    }
+
+    pub fn formula_q_factor(&self) -> f64 {
+        self.1
+    }
+
+    pub fn solved_frequency(&self) -> f64 {
+        self.2
+    }
+
+    pub fn solved_q_factor(&self) -> Option<f64> {
+        self.3
+    }
+
+    pub fn frequency_error(&self) -> f64 {
+        (self.2 - self.0) / self.0
+    }
+
+    pub fn q_factor_error(&self) -> Option<f64> {
+        self.3.map(|q_factor| (q_factor - self.1) / self.1)
+    }
+
+    pub fn agrees(&self) -> bool {
+        self.frequency_error().abs() <= AGREEMENT_TOLERANCE
+            && self
+                .q_factor_error()
+                .is_some_and(|error| error.abs() <= AGREEMENT_TOLERANCE)
+    }
+}
+
+pub fn verify(selection: &Selection) -> Result<Verification, CalcError> {
+    let circuit = gyrator_circuit(selection);
+    let r1 = selection.r1_resistance();
+    let r2 = selection.r2_resistance();
+    let c1 = selection.c1_capacitance();
+    let c2 = selection.c2_capacitance();
+    let formula = GyratorNetwork.frequency(r1, r2, c1, c2);
+    let span = 10f64.powf(SEARCH_DECADES);
+    let sweep = log_sweep(formula / span, formula * span, SEARCH_POINTS);
+    let magnitudes = sweep
+        .iter()
+        .map(|frequency| admittance(&circuit, *frequency))
+        .collect::<Result<Vec<_>, _>>()?;
+    let peak_index = (0..magnitudes.len())
+        .max_by(|a, b| magnitudes[*a].total_cmp(&magnitudes[*b]))
+        .unwrap_or(0);
+    let low = sweep[peak_index.saturating_sub(1)];
+    let high = sweep[(peak_index + 1).min(sweep.len() - 1)];
+    let peak_frequency = refine(low, high, |frequency| {
+        let step = 1.0 + 1e-9;
+
+        Ok(admittance(&circuit, frequency * step)? > admittance(&circuit, frequency / step)?)
+    })?;
+    let half_power = admittance(&circuit, peak_frequency)? / 2f64.sqrt();
+    let lower = (0..peak_index)
+        .rev()
+        .find(|index| magnitudes[*index] < half_power)
+        .map(|index| {
+            refine(sweep[index], peak_frequency, |frequency| {
+                Ok(admittance(&circuit, frequency)? < half_power)
+            })
+        })
+        .transpose()?;
+    let upper = (peak_index + 1..sweep.len())
+        .find(|index| magnitudes[*index] < half_power)
+        .map(|index| {
+            refine(peak_frequency, sweep[index], |frequency| {
+                Ok(admittance(&circuit, frequency)? >= half_power)
+            })
+        })
+        .transpose()?;
+    let q_factor = match (lower, upper) {
+        (Some(lower), Some(upper)) => Some(peak_frequency / (upper - lower)),
+        _ => None,
+    };
+
+    Ok(Verification(
+        formula,
+        GyratorNetwork.q_factor(r1, r2, c1, c2),
+        peak_frequency,
+        q_factor,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solved_q_matches_network_formula() {
+        let selection = Selection::new(470.0, 100000.0, 100e-9, 10e-9);
+        let found = verify(&selection).unwrap();
+
+        assert!(found.agrees());
+        assert!((found.formula_q_factor() - 4.1933).abs() < 1e-3);
+        assert!((found.solved_q_factor().unwrap() - 4.1933).abs() < 0.01);
+        assert!((found.solved_frequency() - 734.13).abs() < 0.1);
+    }
+
+    #[test]
+    fn solved_q_is_below_ideal_q() {
+        let selection = Selection::new(100.0, 1000000.0, 1e-6, 1e-9);
+        let found = verify(&selection).unwrap();
+
+        assert!(found.agrees());
+        assert!(found.solved_q_factor().unwrap() < selection.q_factor());
+    }
+
+    #[test]
+    fn divider_solve() {
+        let mut circuit = Circuit::new(3);
+
+        circuit.add(VoltageSource(1, 0, 1.0));
+        circuit.add(Resistor(1, 2, 1000.0));
+        circuit.add(Resistor(2, 0, 3000.0));
+
+        let solution = circuit.solve(1000.0).unwrap();
+
+        assert!((solution[1].magnitude() - 0.75).abs() < 1e-12);
+    }
+}
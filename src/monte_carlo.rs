@@ -0,0 +1,339 @@
+use crate::tolerance::*;
+use crate::*;
+pub use Distribution::*;
+
+pub const DISTRIBUTION_OPTIONS: [Distribution; 3] = [Uniform, Gaussian, Binned];
+
+pub const DEFAULT_SAMPLES: usize = 10000;
+
+pub const DEFAULT_SEED: u64 = 0x5eed;
+
+const HISTOGRAM_BINS: usize = 20;
+
+const BINNED_FRACTION: f64 = 0.4;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Distribution {
+    Uniform,
+    Gaussian,
+    Binned,
+}
+
+impl Distribution {
+    pub fn as_str(&self) -> String {
+        match self {
+            Uniform => "uniform".into(),
+            Gaussian => "gaussian".into(),
+            Binned => "binned".into(),
+        }
+    }
+
+    fn sample(&self, rng: &mut Rng, tolerance: f64) -> f64 {
+        match self {
+            Uniform => (rng.next_f64() * 2.0 - 1.0) * tolerance,
+            Gaussian => loop {
+                let deviation = rng.next_gaussian() * tolerance / 3.0;
+
+                if deviation.abs() <= tolerance {
+                    break deviation;
+                }
+            },
+            Binned => loop {
+                let deviation = rng.next_gaussian() * tolerance / 3.0;
+
+                if tolerance == 0.0
+                    || (tolerance * BINNED_FRACTION..=tolerance).contains(&deviation.abs())
+                {
+                    break deviation;
+                }
+            },
+        }
+    }
+}
+
+impl From<String> for Distribution {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "uniform" => Uniform,
+            "gaussian" => Gaussian,
+            "binned" => Binned,
+            _ => Uniform,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+
+        let mut value = self.0;
+
+        value = (value ^ (value >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        value = (value ^ (value >> 27)).wrapping_mul(0x94d049bb133111eb);
+        value ^ (value >> 31)
+    }
+
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    pub fn next_gaussian(&mut self) -> f64 {
+        let u1 = 1.0 - self.next_f64();
+        let u2 = self.next_f64();
+
+        (-2.0 * u1.ln()).sqrt() * (2.0 * PI * u2).cos()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Histogram(f64, f64, Vec<usize>);
+
+impl Histogram {
+    fn new(values: &[f64]) -> Histogram {
+        let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let mut bins = vec![0; HISTOGRAM_BINS];
+
+        for value in values {
+            let index = if max > min {
+                ((value - min) / (max - min) * HISTOGRAM_BINS as f64) as usize
+            } else {
+                0
+            };
+
+            bins[index.min(HISTOGRAM_BINS - 1)] += 1;
+        }
+
+        Histogram(min, max, bins)
+    }
+
+    pub fn min(&self) -> f64 {
+        self.0
+    }
+
+    pub fn max(&self) -> f64 {
+        self.1
+    }
+
+    pub fn bins(&self) -> &[usize] {
+        &self.2
+    }
+
+    pub fn bin_width(&self) -> f64 {
+        (self.1 - self.0) / self.2.len() as f64
+    }
+
+    pub fn bin_start(&self, index: usize) -> f64 {
+        self.0 + self.bin_width() * index as f64
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Yield(usize, usize, usize, usize, Histogram, Histogram);
+
+impl Yield {
+    pub fn samples(&self) -> usize {
+        self.0
+    }
+
+    pub fn frequency_passed(&self) -> usize {
+        self.1
+    }
+
+    pub fn q_factor_passed(&self) -> usize {
+        self.2
+    }
+
+    pub fn passed(&self) -> usize {
+        self.3
+    }
+
+    pub fn frequency_yield(&self) -> f64 {
+        self.1 as f64 / self.0 as f64
+    }
+
+    pub fn q_factor_yield(&self) -> f64 {
+        self.2 as f64 / self.0 as f64
+    }
+
+    pub fn total_yield(&self) -> f64 {
+        self.3 as f64 / self.0 as f64
+    }
+
+    pub fn frequency_histogram(&self) -> &Histogram {
+        &self.4
+    }
+
+    pub fn q_factor_histogram(&self) -> &Histogram {
+        &self.5
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct YieldTarget(f64, f64, f64, f64);
+
+impl YieldTarget {
+    pub fn new(
+        frequency: f64,
+        frequency_window: f64,
+        q_factor: f64,
+        q_factor_window: f64,
+    ) -> YieldTarget {
+        YieldTarget(frequency, frequency_window, q_factor, q_factor_window)
+    }
+
+    pub fn frequency(&self) -> f64 {
+        self.0
+    }
+
+    pub fn frequency_window(&self) -> f64 {
+        self.1
+    }
+
+    pub fn q_factor(&self) -> f64 {
+        self.2
+    }
+
+    pub fn q_factor_window(&self) -> f64 {
+        self.3
+    }
+
+    fn validate(&self) -> Result<(), CalcError> {
+        validate_value("frequency", self.0)?;
+        validate_tolerance("frequency", self.1)?;
+        validate_value("q factor", self.2)?;
+        validate_tolerance("q factor", self.3)
+    }
+}
+
+pub fn monte_carlo(
+    selection: &Selection,
+    tolerances: &Tolerances,
+    distribution: &Distribution,
+    target: &YieldTarget,
+    samples: usize,
+    seed: u64,
+) -> Result<Yield, CalcError> {
+    tolerances.validate()?;
+    target.validate()?;
+
+    if samples == 0 {
+        return Err(CalcError::NotPositive("samples", 0.0));
+    }
+
+    let mut rng = Rng::new(seed);
+    let mut frequencies = Vec::with_capacity(samples);
+    let mut q_factors = Vec::with_capacity(samples);
+    let mut frequency_passed = 0;
+    let mut q_factor_passed = 0;
+    let mut passed = 0;
+
+    for _ in 0..samples {
+        let mut vary =
+            |value: f64, tolerance: f64| value * (1.0 + distribution.sample(&mut rng, tolerance));
+        let sample = Selection::new(
+            vary(selection.r1_resistance(), tolerances.r1()),
+            vary(selection.r2_resistance(), tolerances.r2()),
+            vary(selection.c1_capacitance(), tolerances.c1()),
+            vary(selection.c2_capacitance(), tolerances.c2()),
+        );
+        let frequency_ok = ((sample.frequency() - target.frequency()) / target.frequency()).abs()
+            <= target.frequency_window();
+        let q_factor_ok = ((sample.q_factor() - target.q_factor()) / target.q_factor()).abs()
+            <= target.q_factor_window();
+
+        if frequency_ok {
+            frequency_passed += 1;
+        }
+
+        if q_factor_ok {
+            q_factor_passed += 1;
+        }
+
+        if frequency_ok && q_factor_ok {
+            passed += 1;
+        }
+
+        frequencies.push(sample.frequency());
+        q_factors.push(sample.q_factor());
+    }
+
+    Ok(Yield(
+        samples,
+        frequency_passed,
+        q_factor_passed,
+        passed,
+        Histogram::new(&frequencies),
+        Histogram::new(&q_factors),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(distribution: &Distribution, seed: u64) -> Yield {
+        let selection = Selection::new(470.0, 100000.0, 100e-9, 10e-9);
+        let target = YieldTarget::new(selection.frequency(), 0.05, selection.q_factor(), 0.1);
+
+        monte_carlo(
+            &selection,
+            &Tolerances::default(),
+            distribution,
+            &target,
+            1000,
+            seed,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn same_seed_is_deterministic() {
+        for distribution in DISTRIBUTION_OPTIONS {
+            assert_eq!(
+                run(&distribution, DEFAULT_SEED),
+                run(&distribution, DEFAULT_SEED)
+            );
+        }
+    }
+
+    #[test]
+    fn different_seeds_differ() {
+        assert_ne!(run(&Gaussian, 1), run(&Gaussian, 2));
+        assert_ne!(Rng::new(1).next_u64(), Rng::new(2).next_u64());
+    }
+
+    #[test]
+    fn binned_samples_skip_the_centre() {
+        let mut rng = Rng::new(DEFAULT_SEED);
+
+        for _ in 0..10000 {
+            let deviation = Binned.sample(&mut rng, 0.05).abs();
+
+            assert!((0.05 * BINNED_FRACTION..=0.05).contains(&deviation));
+        }
+    }
+
+    #[test]
+    fn histograms_count_every_sample() {
+        let found = run(&Uniform, DEFAULT_SEED);
+
+        assert_eq!(found.samples(), 1000);
+        assert_eq!(
+            found.frequency_histogram().bins().iter().sum::<usize>(),
+            1000
+        );
+        assert_eq!(
+            found.q_factor_histogram().bins().iter().sum::<usize>(),
+            1000
+        );
+        assert!(found.passed() <= found.frequency_passed().min(found.q_factor_passed()));
+    }
+}
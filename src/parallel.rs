@@ -0,0 +1,134 @@
+use crate::diagnostics::*;
+use crate::*;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+const CHUNKS_PER_THREAD: usize = 4;
+
+pub fn available_threads() -> usize {
+    thread::available_parallelism()
+        .map(|threads| threads.get())
+        .unwrap_or(1)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn calculate_parallel(
+    frequency: Arg,
+    q_factor: Arg,
+    r1: Arg,
+    r2: Arg,
+    c1: Arg,
+    c2: Arg,
+    threads: usize,
+    progress: &(dyn Fn(usize, usize) + Sync),
+    cancel: &AtomicBool,
+) -> Result<(Vec<Selection>, Diagnostics), CalcError> {
+    validate(&frequency, &q_factor, &r1, &r2, &c1, &c2)?;
+
+    let threads = threads.max(1);
+    let chunks = threads * CHUNKS_PER_THREAD;
+    let next = AtomicUsize::new(0);
+    let done = AtomicUsize::new(0);
+    let found = Mutex::new(Vec::with_capacity(chunks));
+    let failed = Mutex::new(None);
+    let stop = AtomicBool::new(false);
+
+    progress(0, chunks);
+
+    thread::scope(|scope| {
+        for _ in 0..threads {
+            scope.spawn(|| loop {
+                if cancel.load(Ordering::Relaxed) || stop.load(Ordering::Relaxed) {
+                    break;
+                }
+
+                let chunk = next.fetch_add(1, Ordering::Relaxed);
+
+                if chunk >= chunks {
+                    break;
+                }
+
+                match calculate_chunk(
+                    frequency.clone(),
+                    q_factor.clone(),
+                    r1.clone(),
+                    r2.clone(),
+                    c1.clone(),
+                    c2.clone(),
+                    chunk,
+                    chunks,
+                ) {
+                    Ok(chunk_found) => {
+                        found.lock().unwrap().push(chunk_found);
+                        progress(done.fetch_add(1, Ordering::Relaxed) + 1, chunks);
+                    }
+                    Err(error) => {
+                        failed.lock().unwrap().get_or_insert(error);
+                        stop.store(true, Ordering::Relaxed);
+                    }
+                }
+            });
+        }
+    });
+
+    if let Some(error) = failed.into_inner().unwrap() {
+        return Err(error);
+    }
+
+    if cancel.load(Ordering::Relaxed) {
+        return Err(CalcError::Cancelled);
+    }
+
+    Ok(merge_chunks(found.into_inner().unwrap()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::series::*;
+
+    fn args() -> [Arg; 6] {
+        [
+            ArgWithTolerance(1000.0, 0.05),
+            ArgWithTolerance(2.0, 0.1),
+            ArgWithSeries(Series::E12, 100.0, 100000.0),
+            ArgWithSeries(Series::E12, 100.0, 100000.0),
+            ArgWithSeries(Series::E6, 1e-9, 1e-6),
+            ArgWithSeries(Series::E6, 1e-9, 1e-6),
+        ]
+    }
+
+    #[test]
+    fn matches_sequential_search() {
+        let [frequency, q_factor, r1, r2, c1, c2] = args();
+        let cancel = AtomicBool::new(false);
+        let (found, _) = calculate_parallel(
+            frequency.clone(),
+            q_factor.clone(),
+            r1.clone(),
+            r2.clone(),
+            c1.clone(),
+            c2.clone(),
+            3,
+            &|_, _| {},
+            &cancel,
+        )
+        .unwrap();
+        let expected = calculate(frequency, q_factor, r1, r2, c1, c2).unwrap();
+
+        assert_eq!(found.len(), expected.len());
+        assert!(!cancel.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    fn cancelled_search() {
+        let [frequency, q_factor, r1, r2, c1, c2] = args();
+        let cancel = AtomicBool::new(true);
+
+        assert_eq!(
+            calculate_parallel(frequency, q_factor, r1, r2, c1, c2, 2, &|_, _| {}, &cancel),
+            Err(CalcError::Cancelled)
+        );
+    }
+}
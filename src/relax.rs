@@ -0,0 +1,334 @@
+use crate::diagnostics::*;
+use crate::series::*;
+use crate::units::*;
+use crate::*;
+pub use Relaxation::*;
+
+const ARG_NAMES: [&str; 6] = ["frequency", "q factor", "r1", "r2", "c1", "c2"];
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Relaxation {
+    WidenTolerance(String, f64, f64),
+    DenserSeries(String, Series, Series),
+}
+
+impl Relaxation {
+    pub fn as_str(&self) -> String {
+        match self {
+            WidenTolerance(name, from, to) => format!(
+                "{} tolerance widened from ±{}% to ±{}%",
+                name,
+                format_units(from * 100.0),
+                format_units(to * 100.0)
+            ),
+            DenserSeries(name, from, to) => format!(
+                "{} series moved from {} to {}",
+                name,
+                from.as_str(),
+                to.as_str()
+            ),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Relaxed(Vec<Selection>, Vec<Relaxation>, Diagnostics);
+
+impl Relaxed {
+    pub fn new(
+        selections: Vec<Selection>,
+        relaxations: Vec<Relaxation>,
+        diagnostics: Diagnostics,
+    ) -> Relaxed {
+        Relaxed(selections, relaxations, diagnostics)
+    }
+
+    pub fn selections(&self) -> &[Selection] {
+        &self.0
+    }
+
+    pub fn relaxations(&self) -> &[Relaxation] {
+        &self.1
+    }
+
+    pub fn diagnostics(&self) -> &Diagnostics {
+        &self.2
+    }
+}
+
+fn wider_tolerance(tolerance: f64) -> Option<f64> {
+    TOLERANCE_OPTIONS
+        .iter()
+        .find(|item_tolerance| **item_tolerance > tolerance)
+        .copied()
+}
+
+fn denser_series(series: &Series) -> Option<Series> {
+    SERIES_OPTIONS
+        .iter()
+        .skip_while(|item_series| *item_series != series)
+        .nth(1)
+        .cloned()
+}
+
+fn relax(index: usize, arg: &Arg) -> Option<(Arg, Relaxation)> {
+    let name = ARG_NAMES[index];
+
+    match arg {
+        ArgWithExact(target) if index < 2 => wider_tolerance(0.0).map(|tolerance| {
+            (
+                ArgWithTolerance(*target, tolerance),
+                WidenTolerance(name.into(), 0.0, tolerance),
+            )
+        }),
+        ArgWithTolerance(target, from) => wider_tolerance(*from).map(|tolerance| {
+            (
+                ArgWithTolerance(*target, tolerance),
+                WidenTolerance(name.into(), *from, tolerance),
+            )
+        }),
+        ArgWithBandwidth(measure, target, from) => wider_tolerance(*from).map(|tolerance| {
+            (
+                ArgWithBandwidth(measure.clone(), *target, tolerance),
+                WidenTolerance(name.into(), *from, tolerance),
+            )
+        }),
+        ArgWithSeries(from, min, max) if index >= 2 => denser_series(from).map(|series| {
+            (
+                ArgWithSeries(series.clone(), *min, *max),
+                DenserSeries(name.into(), from.clone(), series),
+            )
+        }),
+        _ => None,
+    }
+}
+
+fn search(args: [Arg; 6]) -> Result<(Vec<Selection>, Diagnostics), CalcError> {
+    let [frequency, q_factor, r1, r2, c1, c2] = args;
+
+    calculate_with_diagnostics(frequency, q_factor, r1, r2, c1, c2)
+}
+
+fn nearest(diagnostics: &Diagnostics) -> f64 {
+    diagnostics
+        .near_misses()
+        .first()
+        .map(|near_miss| near_miss.distance())
+        .unwrap_or(f64::INFINITY)
+}
+
+fn improves(
+    found: &[Selection],
+    diagnostics: &Diagnostics,
+    than: &[Selection],
+    than_diagnostics: &Diagnostics,
+) -> bool {
+    found.len() > than.len()
+        || (found.len() == than.len() && nearest(diagnostics) < nearest(than_diagnostics))
+}
+
+pub fn calculate_relaxed(
+    frequency: Arg,
+    q_factor: Arg,
+    r1: Arg,
+    r2: Arg,
+    c1: Arg,
+    c2: Arg,
+    minimum: usize,
+) -> Result<Relaxed, CalcError> {
+    let mut args = [frequency, q_factor, r1, r2, c1, c2];
+    let mut steps: Vec<(usize, Arg, Arg, Relaxation)> = Vec::new();
+    let (mut results, mut diagnostics) = search(args.clone())?;
+
+    while results.len() < minimum {
+        let mut best: Option<(usize, Arg, Relaxation, Vec<Selection>, Diagnostics)> = None;
+
+        for (index, arg) in args.iter().enumerate() {
+            if let Some((relaxed, relaxation)) = relax(index, arg) {
+                let mut trial = args.clone();
+
+                trial[index] = relaxed.clone();
+
+                let (found, found_diagnostics) = search(trial)?;
+                let is_enough = found.len() >= minimum;
+                let is_better = match &best {
+                    Some((_, _, _, best_found, best_diagnostics)) => {
+                        improves(&found, &found_diagnostics, best_found, best_diagnostics)
+                    }
+                    None => true,
+                };
+
+                if is_better {
+                    best = Some((index, relaxed, relaxation, found, found_diagnostics));
+                }
+
+                if is_enough {
+                    break;
+                }
+            }
+        }
+
+        match best {
+            Some((index, relaxed, relaxation, found, found_diagnostics))
+                if improves(&found, &found_diagnostics, &results, &diagnostics) =>
+            {
+                steps.push((index, args[index].clone(), relaxed.clone(), relaxation));
+                args[index] = relaxed;
+                results = found;
+                diagnostics = found_diagnostics;
+            }
+            _ => break,
+        }
+    }
+
+    for step in (0..steps.len()).rev() {
+        let (index, before, after, _) = &steps[step];
+
+        if args[*index] != *after {
+            continue;
+        }
+
+        let mut trial = args.clone();
+
+        trial[*index] = before.clone();
+
+        let (found, found_diagnostics) = search(trial.clone())?;
+
+        if found.len() >= results.len().min(minimum) {
+            args = trial;
+            results = found;
+            diagnostics = found_diagnostics;
+            steps.remove(step);
+        }
+    }
+
+    let relaxations = steps
+        .into_iter()
+        .map(|(_, _, _, relaxation)| relaxation)
+        .collect();
+
+    Ok(Relaxed(results, relaxations, diagnostics))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn relaxed(frequency: f64, q_factor: f64, minimum: usize) -> Relaxed {
+        let resistance = ArgWithSeries(Series::E6, 1000.0, 100000.0);
+        let capacitance = ArgWithSeries(Series::E6, 1e-9, 1e-6);
+
+        calculate_relaxed(
+            ArgWithExact(frequency),
+            ArgWithExact(q_factor),
+            resistance.clone(),
+            resistance,
+            capacitance.clone(),
+            capacitance,
+            minimum,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn no_relaxation_when_enough() {
+        let found = relaxed(1000.0, 1.0, 0);
+
+        assert!(found.relaxations().is_empty());
+    }
+
+    fn applied(args: &[Arg; 6], relaxations: &[&Relaxation]) -> [Arg; 6] {
+        let mut args = args.clone();
+        let index = |name: &str| {
+            ARG_NAMES
+                .iter()
+                .position(|item_name| *item_name == name)
+                .unwrap()
+        };
+
+        for relaxation in relaxations {
+            match relaxation {
+                WidenTolerance(name, _, to) => {
+                    let index = index(name);
+
+                    args[index] = ArgWithTolerance(args[index].target(), *to);
+                }
+                DenserSeries(name, _, to) => {
+                    let index = index(name);
+
+                    if let ArgWithSeries(_, min, max) = args[index] {
+                        args[index] = ArgWithSeries(to.clone(), min, max);
+                    }
+                }
+            }
+        }
+
+        args
+    }
+
+    #[test]
+    fn every_relaxation_is_needed() {
+        let resistance = ArgWithSeries(Series::E6, 1000.0, 100000.0);
+        let capacitance = ArgWithSeries(Series::E6, 1e-9, 1e-6);
+        let args = [
+            ArgWithExact(50.0),
+            ArgWithExact(20.0),
+            resistance.clone(),
+            resistance,
+            capacitance.clone(),
+            capacitance,
+        ];
+        let found = relaxed(50.0, 20.0, 10);
+        let relaxations = found.relaxations().iter().collect::<Vec<_>>();
+        let name = |relaxation: &Relaxation| match relaxation {
+            WidenTolerance(name, _, _) | DenserSeries(name, _, _) => name.clone(),
+        };
+
+        assert!(!relaxations.is_empty());
+        assert!(found.selections().len() >= 10);
+        assert_eq!(
+            search(applied(&args, &relaxations)).unwrap().0.len(),
+            found.selections().len()
+        );
+
+        for (index, relaxation) in relaxations.iter().enumerate() {
+            let is_last = relaxations[index + 1..]
+                .iter()
+                .all(|later| name(later) != name(relaxation));
+
+            if !is_last {
+                continue;
+            }
+
+            let mut reverted = relaxations.clone();
+
+            reverted.remove(index);
+
+            assert!(
+                search(applied(&args, &reverted)).unwrap().0.len() < 10,
+                "{} was not needed",
+                relaxation.as_str()
+            );
+        }
+    }
+
+    #[test]
+    fn widens_each_tolerance_in_order() {
+        let found = relaxed(1234.0, 3.3, 3);
+
+        assert!(found.selections().len() >= 3);
+
+        for name in ARG_NAMES {
+            let steps = found
+                .relaxations()
+                .iter()
+                .filter_map(|relaxation| match relaxation {
+                    WidenTolerance(item_name, from, to) if *item_name == name => Some((*from, *to)),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+
+            assert!(steps.windows(2).all(|pair| pair[0].1 == pair[1].0));
+        }
+    }
+}
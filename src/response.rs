@@ -0,0 +1,149 @@
+use crate::*;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+pub const SWEEP_POINTS: usize = 200;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Complex(f64, f64);
+
+impl Complex {
+    pub fn new(re: f64, im: f64) -> Complex {
+        Complex(re, im)
+    }
+
+    pub fn re(&self) -> f64 {
+        self.0
+    }
+
+    pub fn im(&self) -> f64 {
+        self.1
+    }
+
+    pub fn magnitude(&self) -> f64 {
+        self.0.hypot(self.1)
+    }
+
+    pub fn phase(&self) -> f64 {
+        self.1.atan2(self.0)
+    }
+}
+
+impl Add for Complex {
+    type Output = Complex;
+
+    fn add(self, other: Complex) -> Complex {
+        Complex(self.0 + other.0, self.1 + other.1)
+    }
+}
+
+impl Sub for Complex {
+    type Output = Complex;
+
+    fn sub(self, other: Complex) -> Complex {
+        Complex(self.0 - other.0, self.1 - other.1)
+    }
+}
+
+impl Neg for Complex {
+    type Output = Complex;
+
+    fn neg(self) -> Complex {
+        Complex(-self.0, -self.1)
+    }
+}
+
+impl Mul for Complex {
+    type Output = Complex;
+
+    fn mul(self, other: Complex) -> Complex {
+        Complex(
+            self.0 * other.0 - self.1 * other.1,
+            self.0 * other.1 + self.1 * other.0,
+        )
+    }
+}
+
+impl Div for Complex {
+    type Output = Complex;
+
+    fn div(self, other: Complex) -> Complex {
+        let denominator = other.0 * other.0 + other.1 * other.1;
+
+        Complex(
+            (self.0 * other.0 + self.1 * other.1) / denominator,
+            (self.1 * other.0 - self.0 * other.1) / denominator,
+        )
+    }
+}
+
+impl From<f64> for Complex {
+    fn from(value: f64) -> Self {
+        Complex(value, 0.0)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ResponsePoint(f64, f64, f64);
+
+impl ResponsePoint {
+    pub fn frequency(&self) -> f64 {
+        self.0
+    }
+
+    pub fn magnitude_db(&self) -> f64 {
+        self.1
+    }
+
+    pub fn phase_degrees(&self) -> f64 {
+        self.2
+    }
+}
+
+pub fn transfer(selection: &Selection, frequency: f64) -> Complex {
+    let r1 = selection.r1_resistance();
+    let r2 = selection.r2_resistance();
+    let c1 = selection.c1_capacitance();
+    let c2 = selection.c2_capacitance();
+    let s = Complex(0.0, 2.0 * PI * frequency);
+    let one = Complex::from(1.0);
+    let numerator = s * (r1 * (c1 + c2)).into() * (one + s * (r1 * c2).into());
+    let denominator = s * s * (r1 * r2 * c1 * c2).into() + s * (r1 * (c1 + c2)).into() + one;
+
+    numerator / denominator
+}
+
+pub fn log_sweep(start: f64, stop: f64, points: usize) -> Vec<f64> {
+    let ratio = (stop / start).ln();
+    let steps = points.max(2) - 1;
+
+    (0..=steps)
+        .map(|step| start * (ratio * step as f64 / steps as f64).exp())
+        .collect()
+}
+
+pub fn frequency_response(
+    selection: &Selection,
+    start: f64,
+    stop: f64,
+    points: usize,
+) -> Result<Vec<ResponsePoint>, CalcError> {
+    validate_value("sweep start", start)?;
+    validate_value("sweep stop", stop)?;
+
+    if start >= stop {
+        return Err(CalcError::InvalidRange("sweep", start, stop));
+    }
+
+    Ok(log_sweep(start, stop, points)
+        .into_iter()
+        .map(|frequency| {
+            let response = transfer(selection, frequency);
+
+            ResponsePoint(
+                frequency,
+                20.0 * response.magnitude().log10(),
+                response.phase().to_degrees(),
+            )
+        })
+        .collect())
+}
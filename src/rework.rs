@@ -0,0 +1,171 @@
+use crate::*;
+
+pub const MAX_CHANGES: usize = 2;
+
+const PART_NAMES: [&str; 4] = ["r1", "r2", "c1", "c2"];
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Change(&'static str, f64, f64);
+
+impl Change {
+    pub fn name(&self) -> &'static str {
+        self.0
+    }
+
+    pub fn from(&self) -> f64 {
+        self.1
+    }
+
+    pub fn to(&self) -> f64 {
+        self.2
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Rework(Selection, Vec<Change>, f64);
+
+impl Rework {
+    pub fn selection(&self) -> &Selection {
+        &self.0
+    }
+
+    pub fn changes(&self) -> &[Change] {
+        &self.1
+    }
+
+    pub fn error(&self) -> f64 {
+        self.2
+    }
+}
+
+fn parts(selection: &Selection) -> [f64; 4] {
+    [
+        selection.r1_resistance(),
+        selection.r2_resistance(),
+        selection.c1_capacitance(),
+        selection.c2_capacitance(),
+    ]
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn rework(
+    r1: f64,
+    r2: f64,
+    c1: f64,
+    c2: f64,
+    frequency: Arg,
+    q_factor: Arg,
+    resistance: Arg,
+    capacitance: Arg,
+) -> Result<Vec<Rework>, CalcError> {
+    let current = analyze(r1, r2, c1, c2)?;
+    let current_parts = parts(&current);
+    let frequency_target = frequency.target();
+    let q_factor_target = q_factor.to_q_factor(frequency_target).target();
+    let mut found = Vec::new();
+
+    for mask in 1..16u32 {
+        let changes = mask.count_ones() as usize;
+
+        if changes > MAX_CHANGES {
+            continue;
+        }
+
+        let arg = |index: usize| {
+            if mask & (1 << index) == 0 {
+                ArgWithExact(current_parts[index])
+            } else if index < 2 {
+                resistance.clone()
+            } else {
+                capacitance.clone()
+            }
+        };
+        let results = calculate(
+            frequency.clone(),
+            q_factor.clone(),
+            arg(0),
+            arg(1),
+            arg(2),
+            arg(3),
+        )?;
+
+        for selection in results {
+            let changed = parts(&selection)
+                .iter()
+                .zip(current_parts)
+                .enumerate()
+                .filter(|(_, (to, from))| *to != from)
+                .map(|(index, (to, from))| Change(PART_NAMES[index], from, *to))
+                .collect::<Vec<_>>();
+
+            if changed.len() == changes {
+                let error = selection.error(frequency_target, q_factor_target);
+
+                found.push(Rework(selection, changed, error));
+            }
+        }
+    }
+
+    found.sort_by(|a, b| a.1.len().cmp(&b.1.len()).then_with(|| a.2.total_cmp(&b.2)));
+
+    Ok(found)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::series::*;
+
+    const CIRCUIT: [f64; 4] = [1000.0, 100000.0, 100e-9, 10e-9];
+
+    fn reworked() -> Vec<Rework> {
+        let [r1, r2, c1, c2] = CIRCUIT;
+
+        rework(
+            r1,
+            r2,
+            c1,
+            c2,
+            ArgWithTolerance(600.0, 0.05),
+            ArgWithTolerance(3.5, 0.25),
+            ArgWithSeries(Series::E12, 100.0, 1000000.0),
+            ArgWithSeries(Series::E12, 1e-9, 1e-6),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn single_part_retunes_first() {
+        let found = reworked();
+
+        assert_eq!(found[0].changes().len(), 1);
+        assert!(found.iter().any(|found| found.changes().len() == 2));
+        assert!(found
+            .windows(2)
+            .all(|pair| pair[0].changes().len() <= pair[1].changes().len()));
+    }
+
+    #[test]
+    fn never_exceeds_max_changes() {
+        assert!(reworked()
+            .iter()
+            .all(|found| !found.changes().is_empty() && found.changes().len() <= MAX_CHANGES));
+    }
+
+    #[test]
+    fn unchanged_parts_match_circuit() {
+        for found in reworked() {
+            for (index, (name, value)) in
+                PART_NAMES.iter().zip(parts(found.selection())).enumerate()
+            {
+                match found.changes().iter().find(|change| change.name() == *name) {
+                    Some(change) => {
+                        assert_eq!(change.from(), CIRCUIT[index]);
+                        assert_eq!(change.to(), value);
+                    }
+                    None => assert_eq!(value, CIRCUIT[index]),
+                }
+            }
+        }
+    }
+}
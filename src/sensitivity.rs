@@ -0,0 +1,127 @@
+use crate::topology::*;
+use crate::*;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Sensitivity(f64, f64);
+
+impl Sensitivity {
+    pub fn frequency(&self) -> f64 {
+        self.0
+    }
+
+    pub fn q_factor(&self) -> f64 {
+        self.1
+    }
+
+    pub fn total(&self) -> f64 {
+        self.0.abs() + self.1.abs()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Sensitivities(Sensitivity, Sensitivity, Sensitivity, Sensitivity);
+
+impl Sensitivities {
+    pub fn r1(&self) -> &Sensitivity {
+        &self.0
+    }
+
+    pub fn r2(&self) -> &Sensitivity {
+        &self.1
+    }
+
+    pub fn c1(&self) -> &Sensitivity {
+        &self.2
+    }
+
+    pub fn c2(&self) -> &Sensitivity {
+        &self.3
+    }
+
+    pub fn total(&self) -> f64 {
+        self.0.total() + self.1.total() + self.2.total() + self.3.total()
+    }
+}
+
+pub fn sensitivities(selection: &Selection, topology: &Topology) -> Sensitivities {
+    match topology {
+        IdealInductor => Sensitivities(
+            Sensitivity(-0.5, -0.5),
+            Sensitivity(-0.5, 0.5),
+            Sensitivity(-0.5, -0.5),
+            Sensitivity(-0.5, 0.5),
+        ),
+        GyratorNetwork => {
+            let c1 = selection.c1_capacitance();
+            let c2 = selection.c2_capacitance();
+
+            Sensitivities(
+                Sensitivity(-0.5, -0.5),
+                Sensitivity(-0.5, 0.5),
+                Sensitivity(-0.5, 0.5 - c1 / (c1 + c2)),
+                Sensitivity(-0.5, 0.5 - c2 / (c1 + c2)),
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn selection() -> Selection {
+        Selection::new(1000.0, 100000.0, 100e-9, 10e-9)
+    }
+
+    #[test]
+    fn ideal_inductor_sums() {
+        let found = sensitivities(&selection(), &IdealInductor);
+        let parts = [found.r1(), found.r2(), found.c1(), found.c2()];
+
+        assert_eq!(parts.iter().map(|part| part.frequency()).sum::<f64>(), -2.0);
+        assert_eq!(parts.iter().map(|part| part.q_factor()).sum::<f64>(), 0.0);
+        assert_eq!(found.r1().q_factor(), -0.5);
+        assert_eq!(found.r2().q_factor(), 0.5);
+    }
+
+    #[test]
+    fn matches_numeric_derivatives() {
+        let selection = selection();
+        let values = [
+            selection.r1_resistance(),
+            selection.r2_resistance(),
+            selection.c1_capacitance(),
+            selection.c2_capacitance(),
+        ];
+
+        for topology in [IdealInductor, GyratorNetwork] {
+            let found = sensitivities(&selection, &topology);
+            let parts = [found.r1(), found.r2(), found.c1(), found.c2()];
+
+            for (index, part) in parts.iter().enumerate() {
+                let step = 1e-6;
+                let mut up = values;
+                let mut down = values;
+
+                up[index] *= 1.0 + step;
+                down[index] *= 1.0 - step;
+
+                let [r1, r2, c1, c2] = up;
+                let [r1_down, r2_down, c1_down, c2_down] = down;
+                let slope =
+                    |high: f64, low: f64| (high / low).ln() / ((1.0 + step) / (1.0 - step)).ln();
+                let frequency = slope(
+                    topology.frequency(r1, r2, c1, c2),
+                    topology.frequency(r1_down, r2_down, c1_down, c2_down),
+                );
+                let q_factor = slope(
+                    topology.q_factor(r1, r2, c1, c2),
+                    topology.q_factor(r1_down, r2_down, c1_down, c2_down),
+                );
+
+                assert!((frequency - part.frequency()).abs() < 1e-6);
+                assert!((q_factor - part.q_factor()).abs() < 1e-6);
+            }
+        }
+    }
+}
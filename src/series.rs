@@ -1,6 +1,7 @@
+use serde::{Deserialize, Serialize};
 pub use Series::*;
 
-#[derive(Clone, Debug, PartialEq, PartialOrd)]
+#[derive(Clone, Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub enum Series {
     E6,
     E12,
@@ -164,7 +165,7 @@ pub fn series_table(table_series: Series, min_value: f64, max_value: f64) -> Vec
         .flatten()
         .collect();
 
-    values.sort_by(|lhs, rhs| lhs.partial_cmp(rhs).unwrap());
+    values.sort_by(|lhs, rhs| lhs.total_cmp(rhs));
 
     values
 }
@@ -0,0 +1,147 @@
+use crate::units::*;
+use crate::*;
+pub use OpAmpModel::*;
+
+pub const OPAMP_OPTIONS: [OpAmpModel; 4] = [IdealOpAmp, Tl072, Ne5532, Lm4562];
+
+pub const FEED_RESISTANCE: f64 = 1000.0;
+
+const SWEEP_RATIO: f64 = 10.0;
+
+const POINTS_PER_DECADE: usize = 100;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum OpAmpModel {
+    IdealOpAmp,
+    Tl072,
+    Ne5532,
+    Lm4562,
+}
+
+impl OpAmpModel {
+    pub fn as_str(&self) -> String {
+        match self {
+            IdealOpAmp => "ideal".into(),
+            Tl072 => "tl072".into(),
+            Ne5532 => "ne5532".into(),
+            Lm4562 => "lm4562".into(),
+        }
+    }
+
+    pub fn subcircuit(&self) -> String {
+        match self {
+            IdealOpAmp => "opamp_ideal".into(),
+            Tl072 => "TL072".into(),
+            Ne5532 => "NE5532".into(),
+            Lm4562 => "LM4562".into(),
+        }
+    }
+
+    pub fn include(&self) -> Option<String> {
+        match self {
+            IdealOpAmp => None,
+            model => Some(format!("{}.lib", model.as_str())),
+        }
+    }
+}
+
+impl From<String> for OpAmpModel {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "ideal" => IdealOpAmp,
+            "tl072" => Tl072,
+            "ne5532" => Ne5532,
+            "lm4562" => Lm4562,
+            _ => IdealOpAmp,
+        }
+    }
+}
+
+pub fn spice_units(value: f64) -> String {
+    format!("{:e}", value)
+}
+
+pub fn netlist_name(selection: &Selection) -> String {
+    format!("gyrator-{}hz.cir", format_units(selection.frequency()))
+}
+
+pub fn netlist(selection: &Selection, model: &OpAmpModel, feed: f64) -> String {
+    let frequency = selection.frequency();
+    let mut lines = vec![
+        format!(
+            "* gyrator filter at {}hz, q {}",
+            format_units(frequency),
+            format_units(selection.q_factor())
+        ),
+        format!("* op-amp model {}", model.as_str()),
+    ];
+
+    match model.include() {
+        Some(include) => lines.push(format!(".include {}", include)),
+        None => lines.extend([
+            ".subckt opamp_ideal inp inn vcc vee out".into(),
+            "E1 out 0 inp inn 1e6".into(),
+            ".ends opamp_ideal".into(),
+        ]),
+    }
+
+    lines.extend([
+        "".into(),
+        ".subckt gyrator a ref vcc vee".into(),
+        format!("C2 a x {}", spice_units(selection.c2_capacitance())),
+        format!("R2 x ref {}", spice_units(selection.r2_resistance())),
+        format!("R1 a y {}", spice_units(selection.r1_resistance())),
+        format!("XU1 x y vcc vee y {}", model.subcircuit()),
+        ".ends gyrator".into(),
+        "".into(),
+        "VCC vcc 0 DC 15".into(),
+        "VEE vee 0 DC -15".into(),
+        "V1 in 0 DC 0 AC 1".into(),
+        format!("RFEED in out {}", spice_units(feed)),
+        format!("C1 out g {}", spice_units(selection.c1_capacitance())),
+        "XGYR g 0 vcc vee gyrator".into(),
+        "".into(),
+        format!(
+            ".ac dec {} {} {}",
+            POINTS_PER_DECADE,
+            spice_units(frequency / SWEEP_RATIO),
+            spice_units(frequency * SWEEP_RATIO)
+        ),
+        ".print ac vdb(out) vp(out)".into(),
+        ".end".into(),
+    ]);
+
+    lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn full_precision_values() {
+        let selection = Selection::new(472.3, 101234.0, 98.76e-9, 10.05e-9);
+        let netlist = netlist(&selection, &IdealOpAmp, 2200.0);
+
+        assert!(netlist.contains("R1 a y 4.723e2\n"));
+        assert!(netlist.contains("R2 x ref 1.01234e5\n"));
+        assert!(netlist.contains("C1 out g 9.876e-8\n"));
+        assert!(netlist.contains("C2 a x 1.005e-8\n"));
+        assert!(netlist.contains("RFEED in out 2.2e3\n"));
+    }
+
+    #[test]
+    fn sweep_spans_ratio_around_frequency() {
+        let selection = Selection::new(1000.0, 1000.0, 1e-6, 1e-6);
+        let frequency = selection.frequency();
+        let netlist = netlist(&selection, &Tl072, FEED_RESISTANCE);
+
+        assert!(netlist.contains(&format!(
+            ".ac dec {} {:e} {:e}\n",
+            POINTS_PER_DECADE,
+            frequency / SWEEP_RATIO,
+            frequency * SWEEP_RATIO
+        )));
+        assert!(netlist.contains(".include tl072.lib\n"));
+    }
+}
@@ -0,0 +1,345 @@
+use crate::series::*;
+use crate::taper::*;
+use crate::*;
+pub use QBehaviour::*;
+
+pub const POT_VALUES: [f64; 9] = [
+    1000.0, 5000.0, 10000.0, 25000.0, 50000.0, 100000.0, 250000.0, 500000.0, 1000000.0,
+];
+
+pub const BEHAVIOUR_OPTIONS: [QBehaviour; 3] = [ConstantQ, ConstantBandwidth, FallingQ];
+
+pub const SWEEP_STEPS: usize = 10;
+
+pub const PLOT_STEPS: usize = 100;
+
+pub const LAW_SPAN_TOLERANCE: f64 = 0.1;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum QBehaviour {
+    ConstantQ,
+    ConstantBandwidth,
+    FallingQ,
+}
+
+impl QBehaviour {
+    pub fn as_str(&self) -> String {
+        match self {
+            ConstantQ => "constant q (dual gang r1 + r2)".into(),
+            ConstantBandwidth => "constant bandwidth (r1)".into(),
+            FallingQ => "falling q (r2)".into(),
+        }
+    }
+
+    fn ratio_exponent(&self) -> f64 {
+        match self {
+            ConstantQ => 1.0,
+            ConstantBandwidth | FallingQ => 2.0,
+        }
+    }
+
+    fn q_factor_at(&self, q_factor: f64, centre: f64, frequency: f64) -> f64 {
+        match self {
+            ConstantQ => q_factor,
+            ConstantBandwidth => q_factor * frequency / centre,
+            FallingQ => q_factor * centre / frequency,
+        }
+    }
+}
+
+impl From<String> for QBehaviour {
+    fn from(value: String) -> Self {
+        BEHAVIOUR_OPTIONS
+            .iter()
+            .find(|item_behaviour| item_behaviour.as_str() == value)
+            .cloned()
+            .unwrap_or(ConstantQ)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Sweep(QBehaviour, f64, Selection, f64, Taper, Option<f64>);
+
+impl Sweep {
+    pub fn behaviour(&self) -> &QBehaviour {
+        &self.0
+    }
+
+    pub fn pot(&self) -> f64 {
+        self.1
+    }
+
+    pub fn selection(&self) -> &Selection {
+        &self.2
+    }
+
+    pub fn error(&self) -> f64 {
+        self.3
+    }
+
+    pub fn taper(&self) -> &Taper {
+        &self.4
+    }
+
+    pub fn law_resistor(&self) -> Option<f64> {
+        self.5
+    }
+
+    pub fn with_taper(&self, taper: Taper, law_resistor: Option<f64>) -> Sweep {
+        Sweep(
+            self.0.clone(),
+            self.1,
+            self.2.clone(),
+            self.3,
+            taper,
+            law_resistor,
+        )
+    }
+
+    pub fn with_pot(&self, pot: f64) -> Sweep {
+        Sweep(
+            self.0.clone(),
+            pot,
+            self.2.clone(),
+            self.3,
+            self.4.clone(),
+            self.5,
+        )
+    }
+
+    pub fn at(&self, rotation: f64) -> Selection {
+        let pot = self.1 * self.4.fraction(rotation);
+        let added = match self.5 {
+            Some(law_resistor) => pot * law_resistor / (pot + law_resistor),
+            None => pot,
+        };
+        let r1 = self.2.r1_resistance();
+        let r2 = self.2.r2_resistance();
+        let c1 = self.2.c1_capacitance();
+        let c2 = self.2.c2_capacitance();
+
+        match self.0 {
+            ConstantQ => Selection::new(r1 + added, r2 + added, c1, c2),
+            ConstantBandwidth => Selection::new(r1 + added, r2, c1, c2),
+            FallingQ => Selection::new(r1, r2 + added, c1, c2),
+        }
+    }
+
+    pub fn points(&self, steps: usize) -> Vec<(f64, Selection)> {
+        (0..=steps)
+            .map(|step| {
+                let rotation = step as f64 / steps.max(1) as f64;
+
+                (rotation, self.at(rotation))
+            })
+            .collect()
+    }
+
+    pub fn span(&self) -> f64 {
+        (self.at(0.0).frequency() / self.at(1.0).frequency()).ln()
+    }
+
+    pub fn unevenness(&self) -> f64 {
+        let points = self.points(PLOT_STEPS);
+        let start = points[0].1.frequency().ln();
+        let span = self.span();
+
+        points
+            .iter()
+            .map(|(rotation, point)| (start - point.frequency().ln() - span * rotation).abs())
+            .fold(0.0, f64::max)
+            / span
+    }
+
+    pub fn suggest_law_resistor(&self) -> Option<Sweep> {
+        let current = self.unevenness();
+        let span = self.span();
+
+        POT_VALUES
+            .iter()
+            .filter(|pot| **pot >= self.1)
+            .flat_map(|pot| {
+                series_table(Series::E12, pot / 100.0, *pot)
+                    .into_iter()
+                    .map(|law_resistor| {
+                        Sweep(
+                            self.0.clone(),
+                            *pot,
+                            self.2.clone(),
+                            self.3,
+                            self.4.clone(),
+                            Some(law_resistor),
+                        )
+                    })
+            })
+            .filter(|sweep| (sweep.span() / span - 1.0).abs() <= LAW_SPAN_TOLERANCE)
+            .filter(|sweep| sweep.unevenness() < current)
+            .min_by(|a, b| a.unevenness().total_cmp(&b.unevenness()))
+    }
+}
+
+fn nearest(table: &[f64], value: f64) -> Option<f64> {
+    table
+        .iter()
+        .min_by(|a, b| (*a / value).ln().abs().total_cmp(&(*b / value).ln().abs()))
+        .copied()
+}
+
+pub fn sweep(
+    lowest: Arg,
+    highest: Arg,
+    q_factor: Arg,
+    behaviour: &QBehaviour,
+    taper: &Taper,
+    resistance: Arg,
+    capacitance: Arg,
+) -> Result<Vec<Sweep>, CalcError> {
+    lowest.validate("lowest frequency")?;
+    taper.validate()?;
+    highest.validate("highest frequency")?;
+
+    let low = lowest.target();
+    let high = highest.target();
+
+    if low >= high {
+        return Err(CalcError::InvalidRange("frequency", low, high));
+    }
+
+    let centre = (low * high).sqrt();
+    let q_factor = q_factor.to_q_factor(centre);
+    let q_factor_target = q_factor.target();
+    let high_q_factor = q_factor.retarget(behaviour.q_factor_at(q_factor_target, centre, high));
+    let resistance_table = resistance.to_checked_table("resistance")?;
+    let fixed_ratio = (high / low).powf(behaviour.ratio_exponent()) - 1.0;
+    let mut found = Vec::new();
+
+    for pot in POT_VALUES {
+        let Some(fixed) = nearest(&resistance_table, pot / fixed_ratio) else {
+            continue;
+        };
+        let (r1, r2) = match behaviour {
+            ConstantQ => (ArgWithExact(fixed), ArgWithExact(fixed)),
+            ConstantBandwidth => (ArgWithExact(fixed), resistance.clone()),
+            FallingQ => (resistance.clone(), ArgWithExact(fixed)),
+        };
+        let results = calculate(
+            highest.clone(),
+            high_q_factor.clone(),
+            r1,
+            r2,
+            capacitance.clone(),
+            capacitance.clone(),
+        )?;
+
+        for selection in results {
+            let design = Sweep(behaviour.clone(), pot, selection, 0.0, taper.clone(), None);
+            let end = design.at(1.0);
+            let error = design.2.error(high, high_q_factor.target())
+                + end.error(low, behaviour.q_factor_at(q_factor_target, centre, low));
+
+            found.push(Sweep(design.0, pot, design.2, error, design.4, None));
+        }
+    }
+
+    found.sort_by(|a, b| a.3.total_cmp(&b.3));
+
+    Ok(found)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn design(behaviour: QBehaviour) -> Sweep {
+        Sweep(
+            behaviour,
+            100000.0,
+            Selection::new(1000.0, 100000.0, 100e-9, 10e-9),
+            0.0,
+            Linear,
+            None,
+        )
+    }
+
+    fn close(a: f64, b: f64) -> bool {
+        (a / b - 1.0).abs() < 1e-9
+    }
+
+    #[test]
+    fn r1_sweep_holds_bandwidth() {
+        let sweep = design(ConstantBandwidth);
+        let (start, end) = (sweep.at(0.0), sweep.at(1.0));
+
+        assert!(end.frequency() < start.frequency());
+        assert!(close(start.bandwidth(), end.bandwidth()));
+        assert!(close(
+            end.q_factor() / start.q_factor(),
+            end.frequency() / start.frequency()
+        ));
+    }
+
+    #[test]
+    fn r2_sweep_falls_in_q() {
+        let sweep = design(FallingQ);
+        let (start, end) = (sweep.at(0.0), sweep.at(1.0));
+        let ratio = end.frequency() / start.frequency();
+
+        assert!(ratio < 1.0);
+        assert!(close(end.q_factor() / start.q_factor(), 1.0 / ratio));
+        assert!(close(end.bandwidth() / start.bandwidth(), ratio * ratio));
+    }
+
+    #[test]
+    fn dual_gang_sweep_holds_q() {
+        let sweep = Sweep(
+            ConstantQ,
+            100000.0,
+            Selection::new(10000.0, 10000.0, 100e-9, 10e-9),
+            0.0,
+            Linear,
+            None,
+        );
+        let (start, end) = (sweep.at(0.0), sweep.at(1.0));
+
+        assert!(end.frequency() < start.frequency());
+        assert!(close(start.q_factor(), end.q_factor()));
+    }
+
+    #[test]
+    fn q_law_matches_behaviour() {
+        assert!(close(ConstantQ.q_factor_at(2.0, 1000.0, 4000.0), 2.0));
+        assert!(close(
+            ConstantBandwidth.q_factor_at(2.0, 1000.0, 4000.0),
+            8.0
+        ));
+        assert!(close(FallingQ.q_factor_at(2.0, 1000.0, 4000.0), 0.5));
+    }
+
+    #[test]
+    fn swept_designs_meet_both_ends() {
+        for behaviour in BEHAVIOUR_OPTIONS {
+            let found = sweep(
+                ArgWithTolerance(100.0, 0.05),
+                ArgWithTolerance(1000.0, 0.05),
+                ArgWithTolerance(4.0, 0.1),
+                &behaviour,
+                &Linear,
+                ArgWithSeries(Series::E24, 100.0, 1000000.0),
+                ArgWithSeries(Series::E12, 1e-9, 1e-6),
+            )
+            .unwrap();
+            let best = &found[0];
+            let (start, end) = (best.at(0.0), best.at(1.0));
+
+            assert!(best.error() < 0.5, "{:?}", behaviour);
+            assert!(close(
+                end.bandwidth() / start.bandwidth(),
+                match behaviour {
+                    ConstantQ => end.frequency() / start.frequency(),
+                    ConstantBandwidth => 1.0,
+                    FallingQ => (end.frequency() / start.frequency()).powi(2),
+                }
+            ));
+        }
+    }
+}
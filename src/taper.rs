@@ -0,0 +1,145 @@
+use crate::*;
+pub use Taper::*;
+
+pub const TAPER_OPTIONS: [Taper; 4] = [Linear, Logarithmic, AntiLogarithmic, Piecewise(Vec::new())];
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Taper {
+    Linear,
+    Logarithmic,
+    AntiLogarithmic,
+    Piecewise(Vec<(f64, f64)>),
+}
+
+impl Taper {
+    pub fn as_str(&self) -> String {
+        match self {
+            Linear => "linear (b)".into(),
+            Logarithmic => "log (a)".into(),
+            AntiLogarithmic => "anti-log (c)".into(),
+            Piecewise(_) => "custom piecewise".into(),
+        }
+    }
+
+    pub fn points(&self) -> Vec<(f64, f64)> {
+        let inner = match self {
+            Linear => Vec::new(),
+            Logarithmic => vec![(0.5, 0.1)],
+            AntiLogarithmic => vec![(0.5, 0.9)],
+            Piecewise(points) => points.clone(),
+        };
+
+        [vec![(0.0, 0.0)], inner, vec![(1.0, 1.0)]].concat()
+    }
+
+    pub fn validate(&self) -> Result<(), CalcError> {
+        let points = self.points();
+
+        if points
+            .windows(2)
+            .all(|pair| pair[0].0 < pair[1].0 && pair[0].1 <= pair[1].1)
+        {
+            Ok(())
+        } else {
+            Err(CalcError::InvalidTaper)
+        }
+    }
+
+    pub fn fraction(&self, rotation: f64) -> f64 {
+        let rotation = rotation.clamp(0.0, 1.0);
+        let points = self.points();
+
+        points
+            .windows(2)
+            .find(|pair| rotation <= pair[1].0)
+            .map(|pair| {
+                let ((x0, y0), (x1, y1)) = (pair[0], pair[1]);
+
+                y0 + (y1 - y0) * (rotation - x0) / (x1 - x0)
+            })
+            .unwrap_or(1.0)
+    }
+}
+
+impl From<String> for Taper {
+    fn from(value: String) -> Self {
+        TAPER_OPTIONS
+            .iter()
+            .find(|item_taper| item_taper.as_str() == value)
+            .cloned()
+            .unwrap_or(Linear)
+    }
+}
+
+pub fn parse_points(value: &str) -> Result<Vec<(f64, f64)>, CalcError> {
+    value
+        .split(',')
+        .filter(|point| !point.trim().is_empty())
+        .map(|point| {
+            let (rotation, fraction) = point.split_once(':').ok_or(CalcError::InvalidTaper)?;
+            let rotation = rotation.trim().trim_end_matches('%').parse::<f64>();
+            let fraction = fraction.trim().trim_end_matches('%').parse::<f64>();
+
+            match (rotation, fraction) {
+                (Ok(rotation), Ok(fraction)) => Ok((rotation / 100.0, fraction / 100.0)),
+                _ => Err(CalcError::InvalidTaper),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_points() {
+        assert_eq!(
+            parse_points("25%:5%, 75:60"),
+            Ok(vec![(0.25, 0.05), (0.75, 0.6)])
+        );
+        assert_eq!(parse_points(""), Ok(Vec::new()));
+    }
+
+    #[test]
+    fn rejects_malformed_points() {
+        assert_eq!(parse_points("50"), Err(CalcError::InvalidTaper));
+        assert_eq!(parse_points("50:half"), Err(CalcError::InvalidTaper));
+        assert_eq!(parse_points("a:10"), Err(CalcError::InvalidTaper));
+    }
+
+    #[test]
+    fn rejects_non_monotonic_taper() {
+        assert_eq!(
+            Piecewise(vec![(0.6, 0.5), (0.4, 0.6)]).validate(),
+            Err(CalcError::InvalidTaper)
+        );
+        assert_eq!(
+            Piecewise(vec![(0.4, 0.6), (0.6, 0.5)]).validate(),
+            Err(CalcError::InvalidTaper)
+        );
+        assert_eq!(
+            Piecewise(vec![(0.5, 1.2)]).validate(),
+            Err(CalcError::InvalidTaper)
+        );
+        assert_eq!(Piecewise(vec![(0.5, 0.2)]).validate(), Ok(()));
+    }
+
+    #[test]
+    fn endpoints_are_fixed() {
+        for taper in [
+            Linear,
+            Logarithmic,
+            AntiLogarithmic,
+            Piecewise(vec![(0.3, 0.1), (0.8, 0.7)]),
+        ] {
+            assert_eq!(taper.fraction(0.0), 0.0);
+            assert_eq!(taper.fraction(1.0), 1.0);
+            assert_eq!(taper.fraction(-0.5), 0.0);
+            assert_eq!(taper.fraction(1.5), 1.0);
+        }
+
+        assert_eq!(Logarithmic.fraction(0.5), 0.1);
+        assert_eq!(AntiLogarithmic.fraction(0.5), 0.9);
+    }
+}
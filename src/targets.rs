@@ -0,0 +1,345 @@
+use crate::diagnostics::*;
+use crate::*;
+pub use TargetPair::*;
+
+pub const TARGET_OPTIONS: [TargetPair; 7] = [
+    FrequencyAndQ,
+    FrequencyAndInductance,
+    FrequencyAndBandwidth,
+    QAndInductance,
+    QAndBandwidth,
+    InductanceAndBandwidth,
+    InductanceAndTestQ,
+];
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Quantity {
+    Frequency,
+    QFactor,
+    Inductance,
+    Bandwidth,
+    TestQFactor,
+}
+
+impl Quantity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Quantity::Frequency => "frequency",
+            Quantity::QFactor => "q factor",
+            Quantity::Inductance => "inductance",
+            Quantity::Bandwidth => "bandwidth",
+            Quantity::TestQFactor => "q at test frequency",
+        }
+    }
+
+    pub fn uses_c1(&self) -> bool {
+        matches!(
+            self,
+            Quantity::Frequency | Quantity::QFactor | Quantity::Bandwidth
+        )
+    }
+
+    fn value(&self, selection: &Selection, test_frequency: f64) -> f64 {
+        match self {
+            Quantity::Frequency => selection.frequency(),
+            Quantity::QFactor => selection.q_factor(),
+            Quantity::Inductance => selection.inductance(),
+            Quantity::Bandwidth => selection.bandwidth(),
+            Quantity::TestQFactor => {
+                2.0 * PI * test_frequency * selection.inductance() / selection.r1_resistance()
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum TargetPair {
+    FrequencyAndQ,
+    FrequencyAndInductance,
+    FrequencyAndBandwidth,
+    QAndInductance,
+    QAndBandwidth,
+    InductanceAndBandwidth,
+    InductanceAndTestQ,
+}
+
+impl TargetPair {
+    pub fn as_str(&self) -> String {
+        let (first, second) = self.quantities();
+
+        format!("{} + {}", first.as_str(), second.as_str())
+    }
+
+    pub fn quantities(&self) -> (Quantity, Quantity) {
+        match self {
+            FrequencyAndQ => (Quantity::Frequency, Quantity::QFactor),
+            FrequencyAndInductance => (Quantity::Frequency, Quantity::Inductance),
+            FrequencyAndBandwidth => (Quantity::Frequency, Quantity::Bandwidth),
+            QAndInductance => (Quantity::QFactor, Quantity::Inductance),
+            QAndBandwidth => (Quantity::QFactor, Quantity::Bandwidth),
+            InductanceAndBandwidth => (Quantity::Inductance, Quantity::Bandwidth),
+            InductanceAndTestQ => (Quantity::Inductance, Quantity::TestQFactor),
+        }
+    }
+
+    pub fn uses(&self, quantity: &Quantity) -> bool {
+        let (first, second) = self.quantities();
+
+        first == *quantity || second == *quantity
+    }
+}
+
+impl From<String> for TargetPair {
+    fn from(value: String) -> Self {
+        TARGET_OPTIONS
+            .iter()
+            .find(|item_pair| item_pair.as_str() == value)
+            .cloned()
+            .unwrap_or(FrequencyAndQ)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Target(Quantity, Arg);
+
+impl Target {
+    pub fn new(quantity: Quantity, value: Arg) -> Target {
+        Target(quantity, value)
+    }
+
+    pub fn quantity(&self) -> &Quantity {
+        &self.0
+    }
+
+    pub fn value(&self) -> &Arg {
+        &self.1
+    }
+
+    fn window(&self, selection: &Selection, test_frequency: f64) -> (f64, f64, f64) {
+        match self.0 {
+            Quantity::QFactor => value_to_tolerance(self.1.to_q_factor(selection.frequency())),
+            Quantity::TestQFactor => value_to_tolerance(self.1.to_q_factor(test_frequency)),
+            _ => value_to_tolerance(self.1.clone()),
+        }
+    }
+
+    fn distance(&self, selection: &Selection, test_frequency: f64) -> f64 {
+        let (target, minimum, maximum) = self.window(selection, test_frequency);
+
+        outside(
+            self.0.value(selection, test_frequency),
+            minimum,
+            maximum,
+            target,
+        )
+    }
+
+    fn reject(&self, diagnostics: &mut Diagnostics) {
+        match self.0 {
+            Quantity::Frequency => diagnostics.frequency_rejections += 1,
+            Quantity::QFactor | Quantity::TestQFactor => diagnostics.q_factor_rejections += 1,
+            Quantity::Inductance => diagnostics.inductance_rejections += 1,
+            Quantity::Bandwidth => diagnostics.bandwidth_rejections += 1,
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn calculate_targets(
+    pair: &TargetPair,
+    first: Arg,
+    second: Arg,
+    test_frequency: f64,
+    r1: Arg,
+    r2: Arg,
+    c1: Arg,
+    c2: Arg,
+) -> Result<(Vec<Selection>, Diagnostics), CalcError> {
+    if *pair == FrequencyAndQ {
+        return calculate_with_diagnostics(first, second, r1, r2, c1, c2);
+    }
+
+    let (first_quantity, second_quantity) = pair.quantities();
+    let first = Target(first_quantity, first);
+    let second = Target(second_quantity, second);
+
+    first.1.validate(first.0.as_str())?;
+    second.1.validate(second.0.as_str())?;
+
+    if pair.uses(&Quantity::TestQFactor) {
+        validate_value("test frequency", test_frequency)?;
+    }
+
+    let r1_table = r1.to_checked_table("r1")?;
+    let r2_table = r2.to_checked_table("r2")?;
+    let mut c1_table = c1.to_checked_table("c1")?;
+    let c2_table = c2.to_checked_table("c2")?;
+    let targets = [first, second];
+
+    if !targets.iter().any(|target| target.0.uses_c1()) {
+        c1_table.truncate(1);
+    }
+
+    let inductance = targets
+        .iter()
+        .find(|target| target.0 == Quantity::Inductance);
+    let mut results = Vec::new();
+    let mut diagnostics = Diagnostics {
+        table_sizes: [
+            r1_table.len(),
+            r2_table.len(),
+            c1_table.len(),
+            c2_table.len(),
+        ],
+        ..Default::default()
+    };
+
+    for r1_value in &r1_table {
+        for r2_value in &r2_table {
+            for c2_value in &c2_table {
+                diagnostics.combinations += 1;
+
+                if let Some(inductance) = inductance {
+                    let (_, minimum, maximum) = value_to_tolerance(inductance.1.clone());
+                    let value = r1_value * r2_value * c2_value;
+
+                    if value < minimum || value > maximum {
+                        inductance.reject(&mut diagnostics);
+                        continue;
+                    }
+                }
+
+                for c1_value in &c1_table {
+                    let selection = Selection::new(*r1_value, *r2_value, *c1_value, *c2_value);
+
+                    let [first_distance, second_distance] = [&targets[0], &targets[1]]
+                        .map(|target| target.distance(&selection, test_frequency));
+
+                    diagnostics.evaluated += 1;
+
+                    match (first_distance > 0.0, second_distance > 0.0) {
+                        (true, true) => {
+                            targets[0].reject(&mut diagnostics);
+                            diagnostics.miss(
+                                selection,
+                                MissedTargets(targets[0].0.clone(), targets[1].0.clone()),
+                                first_distance + second_distance,
+                            );
+                        }
+                        (true, false) => {
+                            targets[0].reject(&mut diagnostics);
+                            diagnostics.miss(
+                                selection,
+                                MissedTarget(targets[0].0.clone()),
+                                first_distance,
+                            );
+                        }
+                        (false, true) => {
+                            targets[1].reject(&mut diagnostics);
+                            diagnostics.miss(
+                                selection,
+                                MissedTarget(targets[1].0.clone()),
+                                second_distance,
+                            );
+                        }
+                        (false, false) => results.push(selection),
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(merge_chunks(vec![(results, diagnostics)]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn search(pair: &TargetPair, first: Arg, second: Arg) -> (Vec<Selection>, Diagnostics) {
+        calculate_targets(
+            pair,
+            first,
+            second,
+            1000.0,
+            ArgWithSeries(Series::E12, 100.0, 100000.0),
+            ArgWithSeries(Series::E12, 100.0, 100000.0),
+            ArgWithSeries(Series::E6, 1e-9, 1e-6),
+            ArgWithSeries(Series::E6, 1e-9, 1e-6),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn inductance_and_test_q() {
+        let (found, _) = search(
+            &InductanceAndTestQ,
+            ArgWithTolerance(1.0, 0.1),
+            ArgWithTolerance(20.0, 0.1),
+        );
+
+        assert!(!found.is_empty());
+
+        for selection in found {
+            let q_factor = 2.0 * PI * 1000.0 * selection.inductance() / selection.r1_resistance();
+
+            assert!((0.9..=1.1).contains(&selection.inductance()));
+            assert!((18.0..=22.0).contains(&q_factor));
+        }
+    }
+
+    #[test]
+    fn records_near_misses() {
+        let (found, diagnostics) = search(
+            &FrequencyAndInductance,
+            ArgWithExact(1234.5),
+            ArgWithTolerance(1.0, 0.05),
+        );
+
+        assert!(found.is_empty());
+        assert!(!diagnostics.near_misses().is_empty());
+        assert!(diagnostics
+            .near_misses()
+            .iter()
+            .all(|near_miss| *near_miss.violation() == MissedTarget(Quantity::Frequency)));
+    }
+
+    #[test]
+    fn rejects_invalid_test_frequency() {
+        assert!(calculate_targets(
+            &InductanceAndTestQ,
+            ArgWithTolerance(1.0, 0.05),
+            ArgWithTolerance(20.0, 0.1),
+            0.0,
+            ArgWithExact(1000.0),
+            ArgWithExact(1000.0),
+            ArgWithExact(1e-6),
+            ArgWithExact(1e-6),
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn pins_c1_without_c1_targets() {
+        let (found, diagnostics) = search(
+            &InductanceAndTestQ,
+            ArgWithTolerance(1.0, 0.1),
+            ArgWithTolerance(20.0, 0.1),
+        );
+
+        assert_eq!(diagnostics.table_sizes()[2], 1);
+        assert!(found
+            .iter()
+            .all(|selection| selection.c1_capacitance() == found[0].c1_capacitance()));
+        assert!(
+            search(
+                &FrequencyAndInductance,
+                ArgWithTolerance(1000.0, 0.1),
+                ArgWithTolerance(1.0, 0.1),
+            )
+            .1
+            .table_sizes()[2]
+                > 1
+        );
+    }
+}
@@ -0,0 +1,284 @@
+use crate::*;
+pub use CapacitorKind::*;
+pub use ResistorKind::*;
+
+pub const REFERENCE_TEMPERATURE: f64 = 25.0;
+
+pub const TEMPERATURE_OPTIONS: [f64; 9] = [-40.0, -20.0, 0.0, 10.0, 25.0, 40.0, 55.0, 70.0, 85.0];
+
+pub const TEMPERATURE_STEPS: usize = 10;
+
+pub const RESISTOR_OPTIONS: [ResistorKind; 3] = [MetalFilm, ThickFilm, CarbonFilm];
+
+pub const CAPACITOR_OPTIONS: [CapacitorKind; 4] = [C0g, Polypropylene, Polyester, X7r];
+
+const X7R_CURVE: [(f64, f64); 7] = [
+    (-55.0, -0.1),
+    (-25.0, -0.05),
+    (0.0, -0.02),
+    (25.0, 0.0),
+    (50.0, 0.01),
+    (85.0, 0.0),
+    (125.0, -0.12),
+];
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum ResistorKind {
+    MetalFilm,
+    ThickFilm,
+    CarbonFilm,
+}
+
+impl ResistorKind {
+    pub fn as_str(&self) -> String {
+        match self {
+            MetalFilm => "metal film (+50ppm)".into(),
+            ThickFilm => "thick film (+100ppm)".into(),
+            CarbonFilm => "carbon film (-400ppm)".into(),
+        }
+    }
+
+    pub fn ppm(&self) -> f64 {
+        match self {
+            MetalFilm => 50.0,
+            ThickFilm => 100.0,
+            CarbonFilm => -400.0,
+        }
+    }
+
+    pub fn drift(&self, temperature: f64) -> f64 {
+        self.ppm() * 1e-6 * (temperature - REFERENCE_TEMPERATURE)
+    }
+}
+
+impl From<String> for ResistorKind {
+    fn from(value: String) -> Self {
+        RESISTOR_OPTIONS
+            .iter()
+            .find(|item_kind| item_kind.as_str() == value)
+            .cloned()
+            .unwrap_or(MetalFilm)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum CapacitorKind {
+    C0g,
+    Polypropylene,
+    Polyester,
+    X7r,
+}
+
+impl CapacitorKind {
+    pub fn as_str(&self) -> String {
+        match self {
+            C0g => "c0g (0ppm)".into(),
+            Polypropylene => "polypropylene film (-200ppm)".into(),
+            Polyester => "polyester film (+400ppm)".into(),
+            X7r => "x7r (±15%)".into(),
+        }
+    }
+
+    pub fn drift(&self, temperature: f64) -> f64 {
+        let ppm = |ppm: f64| ppm * 1e-6 * (temperature - REFERENCE_TEMPERATURE);
+
+        match self {
+            C0g => ppm(0.0),
+            Polypropylene => ppm(-200.0),
+            Polyester => ppm(400.0),
+            X7r => {
+                let temperature = temperature.clamp(X7R_CURVE[0].0, X7R_CURVE[6].0);
+
+                X7R_CURVE
+                    .windows(2)
+                    .find(|pair| temperature <= pair[1].0)
+                    .map(|pair| {
+                        let ((t0, d0), (t1, d1)) = (pair[0], pair[1]);
+
+                        d0 + (d1 - d0) * (temperature - t0) / (t1 - t0)
+                    })
+                    .unwrap_or(0.0)
+            }
+        }
+    }
+}
+
+impl From<String> for CapacitorKind {
+    fn from(value: String) -> Self {
+        CAPACITOR_OPTIONS
+            .iter()
+            .find(|item_kind| item_kind.as_str() == value)
+            .cloned()
+            .unwrap_or(Polyester)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Tempcos(ResistorKind, ResistorKind, CapacitorKind, CapacitorKind);
+
+impl Tempcos {
+    pub fn new(
+        r1: ResistorKind,
+        r2: ResistorKind,
+        c1: CapacitorKind,
+        c2: CapacitorKind,
+    ) -> Tempcos {
+        Tempcos(r1, r2, c1, c2)
+    }
+
+    pub fn r1(&self) -> &ResistorKind {
+        &self.0
+    }
+
+    pub fn r2(&self) -> &ResistorKind {
+        &self.1
+    }
+
+    pub fn c1(&self) -> &CapacitorKind {
+        &self.2
+    }
+
+    pub fn c2(&self) -> &CapacitorKind {
+        &self.3
+    }
+
+    pub fn at(&self, selection: &Selection, temperature: f64) -> Selection {
+        Selection::new(
+            selection.r1_resistance() * (1.0 + self.0.drift(temperature)),
+            selection.r2_resistance() * (1.0 + self.1.drift(temperature)),
+            selection.c1_capacitance() * (1.0 + self.2.drift(temperature)),
+            selection.c2_capacitance() * (1.0 + self.3.drift(temperature)),
+        )
+    }
+}
+
+impl Default for Tempcos {
+    fn default() -> Self {
+        Tempcos(MetalFilm, MetalFilm, Polyester, Polyester)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Drift(Selection, Vec<(f64, Selection)>);
+
+impl Drift {
+    pub fn points(&self) -> &[(f64, Selection)] {
+        &self.1
+    }
+
+    pub fn frequency_drift(&self, selection: &Selection) -> f64 {
+        selection.frequency() / self.0.frequency() - 1.0
+    }
+
+    pub fn q_factor_drift(&self, selection: &Selection) -> f64 {
+        selection.q_factor() / self.0.q_factor() - 1.0
+    }
+
+    pub fn frequency_range(&self) -> (f64, f64) {
+        self.range(|selection| self.frequency_drift(selection))
+    }
+
+    pub fn q_factor_range(&self) -> (f64, f64) {
+        self.range(|selection| self.q_factor_drift(selection))
+    }
+
+    fn range<F>(&self, drift: F) -> (f64, f64)
+    where
+        F: Fn(&Selection) -> f64,
+    {
+        self.1
+            .iter()
+            .map(|(_, selection)| drift(selection))
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), value| {
+                (min.min(value), max.max(value))
+            })
+    }
+}
+
+pub fn drift(
+    selection: &Selection,
+    tempcos: &Tempcos,
+    lowest: f64,
+    highest: f64,
+    steps: usize,
+) -> Result<Drift, CalcError> {
+    if !lowest.is_finite() {
+        return Err(CalcError::NonFinite("lowest temperature"));
+    }
+
+    if !highest.is_finite() {
+        return Err(CalcError::NonFinite("highest temperature"));
+    }
+
+    if lowest > highest {
+        return Err(CalcError::InvalidRange("temperature", lowest, highest));
+    }
+
+    let steps = steps.max(1);
+    let points = (0..=steps)
+        .map(|step| {
+            let temperature = lowest + (highest - lowest) * step as f64 / steps as f64;
+
+            (temperature, tempcos.at(selection, temperature))
+        })
+        .collect();
+
+    Ok(Drift(selection.clone(), points))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn selection() -> Selection {
+        Selection::new(1000.0, 100000.0, 100e-9, 10e-9)
+    }
+
+    fn last(tempcos: &Tempcos, highest: f64) -> (f64, f64) {
+        let selection = selection();
+        let found = drift(&selection, tempcos, REFERENCE_TEMPERATURE, highest, 2).unwrap();
+        let (_, at) = found.points().last().unwrap();
+
+        (found.frequency_drift(at), found.q_factor_drift(at))
+    }
+
+    #[test]
+    fn no_drift_at_reference() {
+        for tempcos in [
+            Tempcos::default(),
+            Tempcos::new(CarbonFilm, ThickFilm, X7r, Polypropylene),
+        ] {
+            assert_eq!(tempcos.at(&selection(), REFERENCE_TEMPERATURE), selection());
+            assert_eq!(last(&tempcos, REFERENCE_TEMPERATURE), (0.0, 0.0));
+        }
+    }
+
+    #[test]
+    fn drift_follows_coefficients() {
+        let (frequency, q_factor) = last(&Tempcos::new(MetalFilm, MetalFilm, C0g, C0g), 75.0);
+
+        assert!((frequency - (1.0 / 1.0025 - 1.0)).abs() < 1e-12);
+        assert!(q_factor.abs() < 1e-12);
+
+        let (frequency, q_factor) = last(&Tempcos::default(), 75.0);
+
+        assert!((frequency - (1.0 / (1.0025 * 1.02) - 1.0)).abs() < 1e-12);
+        assert!(q_factor.abs() < 1e-12);
+
+        let (frequency, q_factor) = last(
+            &Tempcos::new(CarbonFilm, MetalFilm, Polypropylene, C0g),
+            75.0,
+        );
+
+        assert!(frequency > 0.0);
+        assert!((q_factor - (1.0025f64 / (0.98 * 0.99)).sqrt() + 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn rejects_inverted_range() {
+        assert_eq!(
+            drift(&selection(), &Tempcos::default(), 85.0, -40.0, 10),
+            Err(CalcError::InvalidRange("temperature", 85.0, -40.0))
+        );
+    }
+}
@@ -0,0 +1,163 @@
+use crate::*;
+
+pub const COMPONENT_TOLERANCE_OPTIONS: [f64; 7] = [0.001, 0.005, 0.01, 0.02, 0.05, 0.1, 0.2];
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Tolerances(f64, f64, f64, f64);
+
+impl Tolerances {
+    pub fn new(r1: f64, r2: f64, c1: f64, c2: f64) -> Tolerances {
+        Tolerances(r1, r2, c1, c2)
+    }
+
+    pub fn r1(&self) -> f64 {
+        self.0
+    }
+
+    pub fn r2(&self) -> f64 {
+        self.1
+    }
+
+    pub fn c1(&self) -> f64 {
+        self.2
+    }
+
+    pub fn c2(&self) -> f64 {
+        self.3
+    }
+
+    pub fn validate(&self) -> Result<(), CalcError> {
+        validate_tolerance("r1", self.r1())?;
+        validate_tolerance("r2", self.r2())?;
+        validate_tolerance("c1", self.c1())?;
+        validate_tolerance("c2", self.c2())
+    }
+}
+
+impl Default for Tolerances {
+    fn default() -> Self {
+        Tolerances(0.01, 0.01, 0.05, 0.05)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct WorstCase(Selection, Selection, Selection, Selection);
+
+impl WorstCase {
+    pub fn lowest_frequency(&self) -> &Selection {
+        &self.0
+    }
+
+    pub fn highest_frequency(&self) -> &Selection {
+        &self.1
+    }
+
+    pub fn lowest_q_factor(&self) -> &Selection {
+        &self.2
+    }
+
+    pub fn highest_q_factor(&self) -> &Selection {
+        &self.3
+    }
+
+    pub fn frequency_range(&self) -> (f64, f64) {
+        (self.0.frequency(), self.1.frequency())
+    }
+
+    pub fn q_factor_range(&self) -> (f64, f64) {
+        (self.2.q_factor(), self.3.q_factor())
+    }
+}
+
+fn corners(selection: &Selection, tolerances: &Tolerances) -> Vec<Selection> {
+    let limits =
+        |value: f64, tolerance: f64| [value * (1.0 - tolerance), value * (1.0 + tolerance)];
+    let mut found = Vec::with_capacity(16);
+
+    for r1 in limits(selection.r1_resistance(), tolerances.r1()) {
+        for r2 in limits(selection.r2_resistance(), tolerances.r2()) {
+            for c1 in limits(selection.c1_capacitance(), tolerances.c1()) {
+                for c2 in limits(selection.c2_capacitance(), tolerances.c2()) {
+                    found.push(Selection::new(r1, r2, c1, c2));
+                }
+            }
+        }
+    }
+
+    found
+}
+
+pub fn worst_case(selection: &Selection, tolerances: &Tolerances) -> Result<WorstCase, CalcError> {
+    tolerances.validate()?;
+
+    let mut found = WorstCase(
+        selection.clone(),
+        selection.clone(),
+        selection.clone(),
+        selection.clone(),
+    );
+
+    for corner in corners(selection, tolerances) {
+        if corner.frequency() < found.0.frequency() {
+            found.0 = corner.clone();
+        }
+
+        if corner.frequency() > found.1.frequency() {
+            found.1 = corner.clone();
+        }
+
+        if corner.q_factor() < found.2.q_factor() {
+            found.2 = corner.clone();
+        }
+
+        if corner.q_factor() > found.3.q_factor() {
+            found.3 = corner;
+        }
+    }
+
+    Ok(found)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn selection() -> Selection {
+        Selection::new(1000.0, 100000.0, 100e-9, 10e-9)
+    }
+
+    #[test]
+    fn corners_bracket_nominal() {
+        let selection = selection();
+        let worst = worst_case(&selection, &Tolerances::default()).unwrap();
+        let (lowest_frequency, highest_frequency) = worst.frequency_range();
+        let (lowest_q_factor, highest_q_factor) = worst.q_factor_range();
+
+        assert!(lowest_frequency < selection.frequency());
+        assert!(highest_frequency > selection.frequency());
+        assert!(lowest_q_factor < selection.q_factor());
+        assert!(highest_q_factor > selection.q_factor());
+        assert!(worst.lowest_frequency().c1_capacitance() > selection.c1_capacitance());
+        assert!(worst.highest_q_factor().c1_capacitance() < selection.c1_capacitance());
+    }
+
+    #[test]
+    fn zero_tolerance_is_nominal() {
+        let selection = selection();
+        let worst = worst_case(&selection, &Tolerances::new(0.0, 0.0, 0.0, 0.0)).unwrap();
+
+        assert_eq!(
+            worst.frequency_range(),
+            (selection.frequency(), selection.frequency())
+        );
+        assert_eq!(
+            worst.q_factor_range(),
+            (selection.q_factor(), selection.q_factor())
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_tolerance() {
+        assert!(worst_case(&selection(), &Tolerances::new(0.01, 0.01, 1.5, 0.05)).is_err());
+    }
+}
@@ -0,0 +1,42 @@
+use crate::*;
+pub use Topology::*;
+
+pub const TOPOLOGY_OPTIONS: [Topology; 2] = [IdealInductor, GyratorNetwork];
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Topology {
+    IdealInductor,
+    GyratorNetwork,
+}
+
+impl Topology {
+    pub fn as_str(&self) -> String {
+        match self {
+            IdealInductor => "ideal inductor".into(),
+            GyratorNetwork => "gyrator network".into(),
+        }
+    }
+
+    pub fn frequency(&self, r1: f64, r2: f64, c1: f64, c2: f64) -> f64 {
+        1.0 / (2.0 * PI * (r1 * r2 * c1 * c2).sqrt())
+    }
+
+    pub fn q_factor(&self, r1: f64, r2: f64, c1: f64, c2: f64) -> f64 {
+        let q_factor = (r2 * c2 / (r1 * c1)).sqrt();
+
+        match self {
+            IdealInductor => q_factor,
+            GyratorNetwork => q_factor * c1 / (c1 + c2),
+        }
+    }
+}
+
+impl From<String> for Topology {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "ideal inductor" => IdealInductor,
+            "gyrator network" => GyratorNetwork,
+            _ => IdealInductor,
+        }
+    }
+}
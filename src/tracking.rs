@@ -0,0 +1,104 @@
+use crate::sweep::*;
+use crate::*;
+
+pub const TRACKING_OPTIONS: [f64; 6] = [0.01, 0.02, 0.05, 0.1, 0.15, 0.2];
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Tracking(f64, Selection, Selection);
+
+impl Tracking {
+    pub fn rotation(&self) -> f64 {
+        self.0
+    }
+
+    pub fn left(&self) -> &Selection {
+        &self.1
+    }
+
+    pub fn right(&self) -> &Selection {
+        &self.2
+    }
+
+    pub fn frequency_mismatch(&self) -> f64 {
+        self.2.frequency() / self.1.frequency() - 1.0
+    }
+
+    pub fn q_factor_mismatch(&self) -> f64 {
+        self.2.q_factor() / self.1.q_factor() - 1.0
+    }
+}
+
+pub fn tracking(sweep: &Sweep, error: f64, steps: usize) -> Result<Vec<Tracking>, CalcError> {
+    validate_tolerance("tracking error", error.abs())?;
+
+    let right = sweep.with_pot(sweep.pot() * (1.0 + error));
+
+    Ok(sweep
+        .points(steps)
+        .into_iter()
+        .map(|(rotation, left)| Tracking(rotation, left, right.at(rotation)))
+        .collect())
+}
+
+pub fn worst_mismatch(tracking: &[Tracking]) -> Option<&Tracking> {
+    tracking.iter().max_by(|a, b| {
+        a.frequency_mismatch()
+            .abs()
+            .total_cmp(&b.frequency_mismatch().abs())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::series::*;
+    use crate::taper::*;
+
+    fn design() -> Sweep {
+        sweep(
+            ArgWithTolerance(100.0, 0.05),
+            ArgWithTolerance(1000.0, 0.05),
+            ArgWithTolerance(4.0, 0.1),
+            &ConstantQ,
+            &Linear,
+            ArgWithSeries(Series::E24, 100.0, 1000000.0),
+            ArgWithSeries(Series::E12, 1e-9, 1e-6),
+        )
+        .unwrap()
+        .remove(0)
+    }
+
+    fn worst(error: f64) -> f64 {
+        let found = tracking(&design(), error, SWEEP_STEPS).unwrap();
+
+        worst_mismatch(&found).unwrap().frequency_mismatch()
+    }
+
+    #[test]
+    fn zero_error_tracks_exactly() {
+        let found = tracking(&design(), 0.0, SWEEP_STEPS).unwrap();
+
+        assert_eq!(found.len(), SWEEP_STEPS + 1);
+        assert!(found.iter().all(|point| point.frequency_mismatch() == 0.0));
+        assert!(found.iter().all(|point| point.q_factor_mismatch() == 0.0));
+    }
+
+    #[test]
+    fn mismatch_follows_error() {
+        let found = tracking(&design(), 0.1, SWEEP_STEPS).unwrap();
+
+        assert_eq!(found[0].frequency_mismatch(), 0.0);
+        assert!(found[1..]
+            .iter()
+            .all(|point| point.frequency_mismatch() < 0.0));
+        assert!(worst(-0.1) > 0.0);
+        assert!(worst(0.2).abs() > worst(0.1).abs());
+        assert!(worst(0.1).abs() > worst(0.05).abs());
+        assert!(worst(0.1).abs() < 0.1);
+    }
+
+    #[test]
+    fn rejects_invalid_error() {
+        assert!(tracking(&design(), 1.5, SWEEP_STEPS).is_err());
+    }
+}
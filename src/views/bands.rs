@@ -0,0 +1,180 @@
+use crate::*;
+
+#[derive(Properties, PartialEq)]
+pub struct BandsProps {
+    pub count: UseStateHandle<usize>,
+    pub spacing: UseStateHandle<Spacing>,
+    pub optimize: UseStateHandle<Optimize>,
+}
+
+#[function_component(BandsField)]
+pub fn bands_field(
+    BandsProps {
+        count,
+        spacing,
+        optimize,
+    }: &BandsProps,
+) -> Html {
+    let on_count_change = {
+        let state = count.clone();
+
+        move |event: Event| {
+            let element: EventTarget = event
+                .target()
+                .expect("Event should have a target when dispatched");
+            let value = element.unchecked_into::<HtmlInputElement>().value();
+
+            if let Ok(count) = value.parse::<usize>() {
+                state.set(count);
+            }
+        }
+    };
+
+    let on_spacing_change = {
+        let state = spacing.clone();
+
+        move |event: Event| {
+            let element: EventTarget = event
+                .target()
+                .expect("Event should have a target when dispatched");
+
+            state.set(Spacing::from(
+                element.unchecked_into::<HtmlInputElement>().value(),
+            ));
+        }
+    };
+
+    let on_optimize_change = {
+        let state = optimize.clone();
+
+        move |event: Event| {
+            let element: EventTarget = event
+                .target()
+                .expect("Event should have a target when dispatched");
+
+            state.set(Optimize::from(
+                element.unchecked_into::<HtmlInputElement>().value(),
+            ));
+        }
+    };
+
+    html! {
+        <>
+            <div class="field">
+                <label for="bands-count">{"bands"}</label>
+                <select id="bands-count" onchange={on_count_change}>
+                    {
+                        BAND_OPTIONS.iter().map(|item_count| html! {
+                            <option selected={**count == *item_count}>{item_count}</option>
+                        }).collect::<Html>()
+                    }
+                </select>
+            </div>
+            <div class="field">
+                <label for="bands-spacing">{"band spacing"}</label>
+                <select id="bands-spacing" onchange={on_spacing_change}>
+                    {
+                        SPACING_OPTIONS.iter().map(|item_spacing| html! {
+                            <option selected={**spacing == *item_spacing}>{item_spacing.as_str()}</option>
+                        }).collect::<Html>()
+                    }
+                </select>
+            </div>
+            <div class="field">
+                <label for="bands-optimize">{"optimize for"}</label>
+                <select id="bands-optimize" onchange={on_optimize_change}>
+                    {
+                        OPTIMIZE_OPTIONS.iter().map(|item_optimize| html! {
+                            <option selected={**optimize == *item_optimize}>{item_optimize.as_str()}</option>
+                        }).collect::<Html>()
+                    }
+                </select>
+                <p>{"fewer unique values means fewer lines on the bill of materials, an exact r1 or r2 pins the shared pair"}</p>
+            </div>
+        </>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct BandResultsProps {
+    pub results: UseStateHandle<Option<Result<Vec<Band>, CalcError>>>,
+}
+
+#[function_component(BandResults)]
+pub fn band_results(BandResultsProps { results }: &BandResultsProps) -> Html {
+    let Some(Ok(results)) = &**results else {
+        return html! {<Outcome<Vec<Band>> results={results.clone()} />};
+    };
+
+    let found = results
+        .iter()
+        .filter(|band| band.selection().is_some())
+        .count();
+    let bom = bill_of_materials(results);
+
+    html! {
+        <>
+        <h2>{format!("{} of {} bands found", found, results.len())}</h2>
+        <table>
+            <thead>
+                <tr>
+                    <th class="band">{"band"}</th>
+                    <th class="frequency">{"frequency"}</th>
+                    <th class="q-factor">{"q factor"}</th>
+                    <th class="bandwidth">{"bandwidth"}</th>
+                    <th class="inductance">{"inductance"}</th>
+                    <th class="r1-resistance">{"r1"}</th>
+                    <th class="r2-resistance">{"r2"}</th>
+                    <th class="c1-capacitance">{"c1"}</th>
+                    <th class="c2-capacitance">{"c2"}</th>
+                </tr>
+            </thead>
+            <tbody>
+                {
+                    results.iter().map(|band| {
+                        if let Some(result) = band.selection() {
+                            html!{<tr>
+                                <td class="band">{format_units(band.frequency())}</td>
+                                <td class="frequency">{format_units(result.frequency())}</td>
+                                <td class="q-factor">{format_units(result.q_factor())}</td>
+                                <td class="bandwidth">{format_bandwidth(result)}</td>
+                                <td class="inductance">{format_units(result.inductance())}</td>
+                                <td class="r1-resistance">{format_units(result.r1_resistance())}</td>
+                                <td class="r2-resistance">{format_units(result.r2_resistance())}</td>
+                                <td class="c1-capacitance">{format_units(result.c1_capacitance())}</td>
+                                <td class="c2-capacitance">{format_units(result.c2_capacitance())}</td>
+                            </tr>}
+                        } else {
+                            html!{<tr>
+                                <td class="band">{format_units(band.frequency())}</td>
+                                <td class="msg" colspan="8">{"no results found"}</td>
+                            </tr>}
+                        }
+                    }).collect::<Html>()
+                }
+            </tbody>
+        </table>
+        <h2>{format!("{} unique values", bom.len())}</h2>
+        <table>
+            <thead>
+                <tr>
+                    <th class="part">{"part"}</th>
+                    <th class="value">{"value"}</th>
+                    <th class="quantity">{"quantity"}</th>
+                </tr>
+            </thead>
+            <tbody>
+                {
+                    bom.iter().map(|line| {
+                        html!{<tr>
+                            <td class="part">{line.part().as_str()}</td>
+                            <td class="value">{format_units(line.part().value())}</td>
+                            <td class="quantity">{line.quantity()}</td>
+                        </tr>}
+                    }).collect::<Html>()
+                }
+            </tbody>
+        </table>
+        </>
+    }
+}
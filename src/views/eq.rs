@@ -0,0 +1,19 @@
+use crate::*;
+
+pub fn format_gain(gain: &BoostCut) -> String {
+    format!("{:+.1}db / {:+.1}db", gain.boost_db(), gain.cut_db())
+}
+
+#[derive(Properties, PartialEq)]
+pub struct GainProps {
+    pub selection: Selection,
+    pub pot: f64,
+}
+
+#[function_component(GainCell)]
+pub fn gain_cell(GainProps { selection, pot }: &GainProps) -> Html {
+    match boost_cut(selection, *pot) {
+        Ok(gain) => html! {<td class="gain">{format_gain(&gain)}</td>},
+        Err(_) => html! {<td class="gain"></td>},
+    }
+}
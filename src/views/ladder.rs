@@ -0,0 +1,324 @@
+use crate::*;
+
+#[derive(Properties, PartialEq)]
+pub struct LadderProps {
+    pub approximation: UseStateHandle<Approximation>,
+    pub pass: UseStateHandle<Pass>,
+    pub realization: UseStateHandle<Realization>,
+    pub order: UseStateHandle<usize>,
+    pub ripple: UseStateHandle<f64>,
+}
+
+#[function_component(LadderField)]
+pub fn ladder_field(
+    LadderProps {
+        approximation,
+        pass,
+        realization,
+        order,
+        ripple,
+    }: &LadderProps,
+) -> Html {
+    let on_approximation_change = {
+        let state = approximation.clone();
+
+        move |event: Event| {
+            let element: EventTarget = event
+                .target()
+                .expect("Event should have a target when dispatched");
+
+            state.set(Approximation::from(
+                element.unchecked_into::<HtmlInputElement>().value(),
+            ));
+        }
+    };
+
+    let on_pass_change = {
+        let state = pass.clone();
+
+        move |event: Event| {
+            let element: EventTarget = event
+                .target()
+                .expect("Event should have a target when dispatched");
+
+            state.set(Pass::from(
+                element.unchecked_into::<HtmlInputElement>().value(),
+            ));
+        }
+    };
+
+    let on_realization_change = {
+        let state = realization.clone();
+
+        move |event: Event| {
+            let element: EventTarget = event
+                .target()
+                .expect("Event should have a target when dispatched");
+
+            state.set(Realization::from(
+                element.unchecked_into::<HtmlInputElement>().value(),
+            ));
+        }
+    };
+
+    let on_order_change = {
+        let state = order.clone();
+
+        move |event: Event| {
+            let element: EventTarget = event
+                .target()
+                .expect("Event should have a target when dispatched");
+            let value = element.unchecked_into::<HtmlInputElement>().value();
+
+            if let Ok(order) = value.parse::<usize>() {
+                state.set(order);
+            }
+        }
+    };
+
+    let on_ripple_change = {
+        let state = ripple.clone();
+
+        move |event: Event| {
+            let element: EventTarget = event
+                .target()
+                .expect("Event should have a target when dispatched");
+            let value = element.unchecked_into::<HtmlInputElement>().value();
+
+            if let Ok(ripple) = value.trim_end_matches("db").parse::<f64>() {
+                state.set(ripple);
+            }
+        }
+    };
+
+    html! {
+        <>
+            <div class="field">
+                <label for="ladder-approximation">{"response"}</label>
+                <select id="ladder-approximation" onchange={on_approximation_change}>
+                    {
+                        APPROXIMATION_OPTIONS.iter().map(|item_approximation| html! {
+                            <option selected={**approximation == *item_approximation}>{item_approximation.as_str()}</option>
+                        }).collect::<Html>()
+                    }
+                </select>
+            </div>
+            <div class="field">
+                <label for="ladder-pass">{"filter type"}</label>
+                <select id="ladder-pass" onchange={on_pass_change}>
+                    {
+                        PASS_OPTIONS.iter().map(|item_pass| html! {
+                            <option selected={**pass == *item_pass}>{item_pass.as_str()}</option>
+                        }).collect::<Html>()
+                    }
+                </select>
+            </div>
+            <div class="field">
+                <label for="ladder-realization">{"realization"}</label>
+                <select id="ladder-realization" onchange={on_realization_change}>
+                    {
+                        REALIZATION_OPTIONS.iter().map(|item_realization| html! {
+                            <option selected={**realization == *item_realization}>{item_realization.as_str()}</option>
+                        }).collect::<Html>()
+                    }
+                </select>
+                <p>{
+                    match **realization {
+                        SimulatedInductors => "shunt inductors are replaced with gyrators and series inductors with floating gic inductors",
+                        Bruton => "low pass only, series inductors become resistors and shunt capacitors become fdnr gics",
+                    }
+                }</p>
+            </div>
+            <div class="field">
+                <label for="ladder-order">{"order"}</label>
+                <select id="ladder-order" onchange={on_order_change}>
+                    {
+                        ORDER_OPTIONS.iter().map(|item_order| html! {
+                            <option selected={**order == *item_order}>{item_order}</option>
+                        }).collect::<Html>()
+                    }
+                </select>
+            </div>
+            {
+                if **approximation == Chebyshev {
+                    html! {
+                        <div class="field">
+                            <label for="ladder-ripple">{"ripple"}</label>
+                            <select id="ladder-ripple" onchange={on_ripple_change}>
+                                {
+                                    RIPPLE_OPTIONS.iter().map(|item_ripple| html! {
+                                        <option selected={**ripple == *item_ripple}>{format!("{}db", item_ripple)}</option>
+                                    }).collect::<Html>()
+                                }
+                            </select>
+                        </div>
+                    }
+                } else {
+                    html! {}
+                }
+            }
+        </>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct LadderResultsProps {
+    pub results: UseStateHandle<Option<Result<Ladder, CalcError>>>,
+}
+
+#[function_component(LadderResults)]
+pub fn ladder_results(LadderResultsProps { results }: &LadderResultsProps) -> Html {
+    let selected = use_state(|| None::<Selection>);
+    let topology = use_state(|| GyratorNetwork);
+
+    let Some(Ok(ladder)) = &**results else {
+        return html! {<Outcome<Ladder> results={results.clone()} />};
+    };
+
+    html! {
+        <>
+        <h2>{"ladder"}</h2>
+        <p>{format!(
+            "source {} {}, load {} {}",
+            ladder.source().as_str(),
+            format_units(ladder.source().value()),
+            ladder.load().as_str(),
+            format_units(ladder.load().value())
+        )}</p>
+        <table>
+            <thead>
+                <tr>
+                    <th class="position">{"#"}</th>
+                    <th class="element">{"element"}</th>
+                    <th class="value">{"value"}</th>
+                    <th class="frequency">{"simulated"}</th>
+                    <th class="q-factor">{"q at cutoff"}</th>
+                    <th class="parts">{"parts"}</th>
+                    <th class="netlist">{"spice"}</th>
+                </tr>
+            </thead>
+            <tbody>
+                {
+                    ladder.elements().iter().enumerate().map(|(index, element)| {
+                        let simulated = match element {
+                            ShuntInductor(_, Some(selection)) => {
+                                let on_netlist_click = {
+                                    let selection = selection.clone();
+
+                                    Callback::from(move |event: MouseEvent| {
+                                        event.stop_propagation();
+
+                                        let element: web_sys::Element = event
+                                            .current_target()
+                                            .expect("Event should have a target when dispatched")
+                                            .unchecked_into();
+                                        let href = format_download(&netlist(&selection, &IdealOpAmp, FEED_RESISTANCE));
+
+                                        element.set_attribute("href", &href).ok();
+                                    })
+                                };
+
+                                html! {
+                                    <>
+                                    <td class="frequency">{format!("gyrator {}", format_units(selection.inductance()))}</td>
+                                    <td class="q-factor">{format_units(selection.q_factor())}</td>
+                                    <td class="parts">{format!(
+                                        "r1 {}, r2 {}, c2 {}",
+                                        format_units(selection.r1_resistance()),
+                                        format_units(selection.r2_resistance()),
+                                        format_units(selection.c2_capacitance())
+                                    )}</td>
+                                    <td class="netlist">
+                                        <a download={netlist_name(selection)} href="#" onclick={on_netlist_click}>
+                                            {"download netlist"}
+                                        </a>
+                                    </td>
+                                    </>
+                                }
+                            }
+                            SeriesInductor(_, Some(gic)) => html! {
+                                <>
+                                <td class="frequency">{format!("gic {}", format_units(gic.value()))}</td>
+                                <td class="q-factor"></td>
+                                <td class="parts">{format!(
+                                    "r1-r3 {}, r5 {}, c4 {}",
+                                    format_units(gic.resistance()),
+                                    format_units(gic.r5_resistance()),
+                                    format_units(gic.capacitance())
+                                )}</td>
+                                <td class="netlist"></td>
+                                </>
+                            },
+                            ShuntFdnr(_, Some(gic)) => html! {
+                                <>
+                                <td class="frequency">{format!("gic {}", format_units(gic.value()))}</td>
+                                <td class="q-factor"></td>
+                                <td class="parts">{format!(
+                                    "r2-r4 {}, c1 c5 {}",
+                                    format_units(gic.r5_resistance()),
+                                    format_units(gic.capacitance())
+                                )}</td>
+                                <td class="netlist"></td>
+                                </>
+                            },
+                            ShuntInductor(_, None) | SeriesInductor(_, None) | ShuntFdnr(_, None) => {
+                                html! {<td colspan="4">{"no simulated element found in range"}</td>}
+                            }
+                            _ => html! {<td colspan="4"></td>},
+                        };
+                        let (class, onclick) = match element {
+                            ShuntInductor(_, Some(selection)) => {
+                                let selected = selected.clone();
+                                let selection = selection.clone();
+                                let class = if selected.as_ref() == Some(&selection) {
+                                    "result selected"
+                                } else {
+                                    "result"
+                                };
+
+                                (class, Callback::from(move |_| selected.set(Some(selection.clone()))))
+                            }
+                            _ => ("", Callback::noop()),
+                        };
+
+                        html! {<tr {class} {onclick}>
+                            <td class="position">{index + 1}</td>
+                            <td class="element">{element.as_str()}</td>
+                            <td class="value">{format_units(element.value())}</td>
+                            {simulated}
+                        </tr>}
+                    }).collect::<Html>()
+                }
+            </tbody>
+        </table>
+        {
+            match &*selected {
+                Some(selection) if ladder.elements().iter().any(|element| matches!(element, ShuntInductor(_, Some(found)) if found == selection)) => {
+                    html! {<Detail selection={selection.clone()} topology={topology.clone()} />}
+                }
+                _ => html! {<p>{"click a gyrator row to see its worst case tolerances"}</p>},
+            }
+        }
+
+        <h2>{"components"}</h2>
+        <table>
+            <thead>
+                <tr>
+                    <th class="part">{"part"}</th>
+                    <th class="name">{"name"}</th>
+                    <th class="value">{"value"}</th>
+                </tr>
+            </thead>
+            <tbody>
+                {
+                    ladder.components().iter().map(|component| html! {<tr>
+                        <td class="part">{component.part().as_str()}</td>
+                        <td class="name">{component.name()}</td>
+                        <td class="value">{format_units(component.part().value())}</td>
+                    </tr>}).collect::<Html>()
+                }
+            </tbody>
+        </table>
+        </>
+    }
+}
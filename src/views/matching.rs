@@ -0,0 +1,85 @@
+use crate::*;
+
+#[derive(Properties, PartialEq)]
+pub struct MeasuredProps {
+    pub id: &'static str,
+    pub name: &'static str,
+    pub value: UseStateHandle<String>,
+}
+
+#[function_component(MeasuredField)]
+pub fn measured_field(MeasuredProps { id, name, value }: &MeasuredProps) -> Html {
+    let on_measured_change = {
+        let state = value.clone();
+
+        move |event: Event| {
+            let element: EventTarget = event
+                .target()
+                .expect("Event should have a target when dispatched");
+
+            state.set(element.unchecked_into::<HtmlInputElement>().value());
+        }
+    };
+
+    html! {
+        <div class="field">
+            <label for={*id}>{*name}</label>
+            <input
+                id={*id}
+                placeholder="99.2n, 100.4n, 101n"
+                value={(**value).clone()}
+                onchange={on_measured_change}
+            />
+            <p>{format!("measured values of the {} parts on hand", name)}</p>
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct MatchResultsProps {
+    pub results: UseStateHandle<Option<Result<MatchedPair, CalcError>>>,
+}
+
+#[function_component(MatchResults)]
+pub fn match_results(MatchResultsProps { results }: &MatchResultsProps) -> Html {
+    let Some(Ok(pair)) = &**results else {
+        return html! {<Outcome<MatchedPair> results={results.clone()} />};
+    };
+
+    html! {
+        <>
+        <h2>{"matched pair"}</h2>
+        <p>{format!(
+            "channels differ by {:+.2}% in frequency and {:+.2}% in q factor",
+            pair.frequency_mismatch() * 100.0,
+            pair.q_factor_mismatch() * 100.0
+        )}</p>
+        <table>
+            <thead>
+                <tr>
+                    <th class="channel">{"channel"}</th>
+                    <th class="frequency">{"frequency"}</th>
+                    <th class="q-factor">{"q factor"}</th>
+                    <th class="r1-resistance">{"r1"}</th>
+                    <th class="r2-resistance">{"r2"}</th>
+                    <th class="c1-capacitance">{"c1"}</th>
+                    <th class="c2-capacitance">{"c2"}</th>
+                </tr>
+            </thead>
+            <tbody>
+                {
+                    [("left", pair.left()), ("right", pair.right())].iter().map(|(channel, result)| html! {<tr>
+                        <td class="channel">{*channel}</td>
+                        <td class="frequency">{format_units(result.frequency())}</td>
+                        <td class="q-factor">{format_units(result.q_factor())}</td>
+                        <td class="r1-resistance">{format_units(result.r1_resistance())}</td>
+                        <td class="r2-resistance">{format_units(result.r2_resistance())}</td>
+                        <td class="c1-capacitance">{format_units(result.c1_capacitance())}</td>
+                        <td class="c2-capacitance">{format_units(result.c2_capacitance())}</td>
+                    </tr>}).collect::<Html>()
+                }
+            </tbody>
+        </table>
+        </>
+    }
+}
@@ -0,0 +1,62 @@
+use crate::*;
+
+#[derive(Properties, PartialEq)]
+pub struct VerifyProps {
+    pub selection: Selection,
+}
+
+#[function_component(VerifyView)]
+pub fn verify_view(VerifyProps { selection }: &VerifyProps) -> Html {
+    let format_error = |error: f64| format!("{:+.2}%", error * 100.0);
+
+    match verify(selection) {
+        Ok(found) => html! {
+            <>
+            <h2>{"circuit check"}</h2>
+            {
+                if found.agrees() {
+                    html! {<p>{"the solved circuit agrees with the formula values"}</p>}
+                } else {
+                    html! {<p class="error">{format!(
+                        "the solved circuit disagrees with the formula values by more than {}%",
+                        format_units(AGREEMENT_TOLERANCE * 100.0)
+                    )}</p>}
+                }
+            }
+            <table>
+                <thead>
+                    <tr>
+                        <th class="stage">{"value"}</th>
+                        <th class="count">{"gyrator network formula"}</th>
+                        <th class="count">{"solved"}</th>
+                        <th class="count">{"difference"}</th>
+                    </tr>
+                </thead>
+                <tbody>
+                    <tr>
+                        <td class="stage">{"frequency"}</td>
+                        <td class="count">{format_units(found.formula_frequency())}</td>
+                        <td class="count">{format_units(found.solved_frequency())}</td>
+                        <td class="count">{format_error(found.frequency_error())}</td>
+                    </tr>
+                    <tr>
+                        <td class="stage">{"network q factor"}</td>
+                        <td class="count">{format_units(found.formula_q_factor())}</td>
+                        <td class="count">{
+                            found.solved_q_factor().map(format_units).unwrap_or("no peak".into())
+                        }</td>
+                        <td class="count">{
+                            found.q_factor_error().map(format_error).unwrap_or_default()
+                        }</td>
+                    </tr>
+                </tbody>
+            </table>
+            <p>{format!(
+                "the solved q is checked against the gyrator network q = q·c1/(c1+c2), the results table shows the ideal inductor q {}",
+                format_units(selection.q_factor())
+            )}</p>
+            </>
+        },
+        Err(error) => html! {<p class="error">{error.to_string()}</p>},
+    }
+}
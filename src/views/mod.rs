@@ -0,0 +1,33 @@
+pub mod bands;
+pub mod eq;
+pub mod ladder;
+pub mod matching;
+pub mod mna;
+pub mod monte_carlo;
+pub mod response;
+pub mod rework;
+pub mod search;
+pub mod sensitivity;
+pub mod sweep;
+pub mod targets;
+pub mod tempco;
+pub mod tolerance;
+pub mod tracking;
+
+use crate::*;
+
+#[derive(Properties, PartialEq)]
+pub struct OutcomeProps<T: PartialEq + 'static> {
+    pub results: UseStateHandle<Option<Result<T, CalcError>>>,
+}
+
+#[function_component(Outcome)]
+pub fn outcome<T: PartialEq + 'static>(OutcomeProps { results }: &OutcomeProps<T>) -> Html {
+    match &**results {
+        Some(Err(error)) => html! {<p class="error">{error.to_string()}</p>},
+        Some(Ok(_)) => html! {},
+        None => html! {
+            <p>{"click \"calculate\" above to begin"}</p>
+        },
+    }
+}
@@ -0,0 +1,166 @@
+use crate::*;
+
+#[derive(Properties, PartialEq)]
+pub struct HistogramProps {
+    pub name: &'static str,
+    pub histogram: Histogram,
+    pub target: f64,
+    pub window: f64,
+}
+
+#[function_component(HistogramTable)]
+pub fn histogram_table(
+    HistogramProps {
+        name,
+        histogram,
+        target,
+        window,
+    }: &HistogramProps,
+) -> Html {
+    let largest = histogram.bins().iter().copied().max().unwrap_or(0).max(1);
+
+    html! {
+        <table class="histogram">
+            <thead>
+                <tr>
+                    <th class="bin">{*name}</th>
+                    <th class="count">{"samples"}</th>
+                    <th class="bar"></th>
+                </tr>
+            </thead>
+            <tbody>
+                {
+                    histogram.bins().iter().enumerate().map(|(index, count)| {
+                        let start = histogram.bin_start(index);
+                        let end = start + histogram.bin_width();
+                        let middle = (start + end) / 2.0;
+                        let class = if ((middle - target) / target).abs() <= *window {
+                            "bar pass"
+                        } else {
+                            "bar"
+                        };
+                        let style = format!("width: {}%", count * 100 / largest);
+
+                        html!{<tr>
+                            <td class="bin">{format!("{} to {}", format_units(start), format_units(end))}</td>
+                            <td class="count">{count}</td>
+                            <td class="bar"><div {class} {style}></div></td>
+                        </tr>}
+                    }).collect::<Html>()
+                }
+            </tbody>
+        </table>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct YieldProps {
+    pub selection: Selection,
+    pub tolerances: Tolerances,
+    pub target: (f64, f64),
+}
+
+#[function_component(YieldView)]
+pub fn yield_view(
+    YieldProps {
+        selection,
+        tolerances,
+        target,
+    }: &YieldProps,
+) -> Html {
+    let distribution = use_state(|| Uniform);
+    let frequency_window = use_state(|| 0.05);
+    let q_factor_window = use_state(|| 0.1);
+    let (frequency, q_factor) = *target;
+    let yield_target = YieldTarget::new(frequency, *frequency_window, q_factor, *q_factor_window);
+
+    let on_distribution_change = {
+        let distribution = distribution.clone();
+
+        Callback::from(move |event: Event| {
+            let element: EventTarget = event
+                .target()
+                .expect("Event should have a target when dispatched");
+            let input = element.unchecked_into::<HtmlInputElement>();
+
+            distribution.set(Distribution::from(input.value()));
+        })
+    };
+
+    let found = use_memo(
+        |(selection, tolerances, distribution, yield_target)| {
+            monte_carlo(
+                selection,
+                tolerances,
+                distribution,
+                yield_target,
+                DEFAULT_SAMPLES,
+                DEFAULT_SEED,
+            )
+        },
+        (
+            selection.clone(),
+            tolerances.clone(),
+            (*distribution).clone(),
+            yield_target,
+        ),
+    );
+
+    let found = match &*found {
+        Ok(found) => html! {
+            <>
+            <p>{format!(
+                "{}% of {} boards within ±{}% of {}hz and ±{}% of q {} ({}% pass frequency, {}% pass q factor)",
+                format_units(found.total_yield() * 100.0),
+                found.samples(),
+                format_units(*frequency_window * 100.0),
+                format_units(frequency),
+                format_units(*q_factor_window * 100.0),
+                format_units(q_factor),
+                format_units(found.frequency_yield() * 100.0),
+                format_units(found.q_factor_yield() * 100.0)
+            )}</p>
+            <HistogramTable
+                name="frequency"
+                histogram={found.frequency_histogram().clone()}
+                target={frequency}
+                window={*frequency_window} />
+            <HistogramTable
+                name="q factor"
+                histogram={found.q_factor_histogram().clone()}
+                target={q_factor}
+                window={*q_factor_window} />
+            </>
+        },
+        Err(error) => html! {<p class="error">{error.to_string()}</p>},
+    };
+
+    html! {
+        <>
+        <h2>{"monte carlo yield"}</h2>
+        <div class="fieldset">
+            <div class="field">
+                <label for="distribution">{"distribution"}</label>
+                <select id="distribution" onchange={on_distribution_change}>
+                    {
+                        DISTRIBUTION_OPTIONS.iter().map(|item_distribution| html! {
+                            <option selected={*distribution == *item_distribution}>{item_distribution.as_str()}</option>
+                        }).collect::<Html>()
+                    }
+                </select>
+            </div>
+            <ToleranceField
+                id="frequency-window"
+                name="frequency window"
+                options={&TOLERANCE_OPTIONS[..]}
+                value={frequency_window} />
+            <ToleranceField
+                id="q-factor-window"
+                name="q factor window"
+                options={&TOLERANCE_OPTIONS[..]}
+                value={q_factor_window} />
+        </div>
+        {found}
+        </>
+    }
+}
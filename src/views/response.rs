@@ -0,0 +1,195 @@
+use crate::*;
+
+pub const PLOT_COLOURS: [&str; 6] = [
+    "hsl(270deg, 30%, 40%)",
+    "hsl(190deg, 50%, 40%)",
+    "hsl(30deg, 70%, 45%)",
+    "hsl(130deg, 40%, 40%)",
+    "hsl(340deg, 50%, 45%)",
+    "hsl(60deg, 60%, 35%)",
+];
+
+pub const PLOT_WIDTH: f64 = 600.0;
+pub const PLOT_HEIGHT: f64 = 160.0;
+pub const PLOT_MARGIN: f64 = 40.0;
+
+#[derive(Properties, PartialEq)]
+pub struct PlotProps {
+    pub name: &'static str,
+    pub start: f64,
+    pub stop: f64,
+    pub min: f64,
+    pub max: f64,
+    pub step: f64,
+    pub curves: Vec<Vec<(f64, f64)>>,
+}
+
+#[function_component(Plot)]
+pub fn plot(
+    PlotProps {
+        name,
+        start,
+        stop,
+        min,
+        max,
+        step,
+        curves,
+    }: &PlotProps,
+) -> Html {
+    let x =
+        |frequency: f64| PLOT_MARGIN + (frequency / start).ln() / (stop / start).ln() * PLOT_WIDTH;
+    let y = |value: f64| (max - value.clamp(*min, *max)) / (max - min) * PLOT_HEIGHT + 10.0;
+    let decades = (start.log10().ceil() as i32..=stop.log10().floor() as i32)
+        .map(|decade| 10f64.powi(decade))
+        .collect::<Vec<_>>();
+    let levels = (0..=((max - min) / step).round() as usize)
+        .map(|level| min + step * level as f64)
+        .collect::<Vec<_>>();
+
+    html! {
+        <svg
+            class="plot"
+            viewBox={format!("0 0 {} {}", PLOT_WIDTH + PLOT_MARGIN + 10.0, PLOT_HEIGHT + 40.0)}>
+            <text class="name" x={PLOT_MARGIN.to_string()} y="8">{*name}</text>
+            {
+                decades.iter().map(|decade| html! {
+                    <>
+                    <line
+                        class="grid"
+                        x1={x(*decade).to_string()}
+                        x2={x(*decade).to_string()}
+                        y1="10"
+                        y2={(PLOT_HEIGHT + 10.0).to_string()} />
+                    <text
+                        class="label"
+                        x={x(*decade).to_string()}
+                        y={(PLOT_HEIGHT + 25.0).to_string()}>
+                        {format!("{}hz", format_units(*decade))}
+                    </text>
+                    </>
+                }).collect::<Html>()
+            }
+            {
+                levels.iter().map(|level| html! {
+                    <>
+                    <line
+                        class="grid"
+                        x1={PLOT_MARGIN.to_string()}
+                        x2={(PLOT_MARGIN + PLOT_WIDTH).to_string()}
+                        y1={y(*level).to_string()}
+                        y2={y(*level).to_string()} />
+                    <text class="label level" x={(PLOT_MARGIN - 5.0).to_string()} y={y(*level).to_string()}>
+                        {format!("{}", level)}
+                    </text>
+                    </>
+                }).collect::<Html>()
+            }
+            {
+                curves.iter().enumerate().map(|(index, curve)| {
+                    let points = curve
+                        .iter()
+                        .map(|(frequency, value)| format!("{:.1},{:.1}", x(*frequency), y(*value)))
+                        .collect::<Vec<_>>()
+                        .join(" ");
+
+                    html! {
+                        <polyline
+                            class="curve"
+                            stroke={PLOT_COLOURS[index % PLOT_COLOURS.len()]}
+                            {points} />
+                    }
+                }).collect::<Html>()
+            }
+        </svg>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct BodeProps {
+    pub selections: Vec<Selection>,
+}
+
+#[function_component(BodePlot)]
+pub fn bode_plot(BodeProps { selections }: &BodeProps) -> Html {
+    let lowest = selections
+        .iter()
+        .map(|selection| selection.frequency())
+        .fold(f64::INFINITY, f64::min);
+    let highest = selections
+        .iter()
+        .map(|selection| selection.frequency())
+        .fold(f64::NEG_INFINITY, f64::max);
+    let start = 10f64.powf((lowest / 10.0).log10().floor());
+    let stop = 10f64.powf((highest * 10.0).log10().ceil());
+    let responses = selections
+        .iter()
+        .map(|selection| frequency_response(selection, start, stop, SWEEP_POINTS))
+        .collect::<Result<Vec<_>, _>>();
+
+    match responses {
+        Ok(responses) => {
+            let peak = responses
+                .iter()
+                .flatten()
+                .map(|point| point.magnitude_db())
+                .fold(f64::NEG_INFINITY, f64::max);
+            let magnitude_max = (peak / 6.0).ceil() * 6.0;
+            let magnitudes = responses
+                .iter()
+                .map(|response| {
+                    response
+                        .iter()
+                        .map(|point| (point.frequency(), point.magnitude_db()))
+                        .collect()
+                })
+                .collect::<Vec<_>>();
+            let phases = responses
+                .iter()
+                .map(|response| {
+                    response
+                        .iter()
+                        .map(|point| (point.frequency(), point.phase_degrees()))
+                        .collect()
+                })
+                .collect::<Vec<_>>();
+
+            html! {
+                <div class="bode">
+                    <Plot
+                        name="magnitude (db)"
+                        {start}
+                        {stop}
+                        min={magnitude_max - 48.0}
+                        max={magnitude_max}
+                        step={12.0}
+                        curves={magnitudes} />
+                    <Plot
+                        name="phase (degrees)"
+                        {start}
+                        {stop}
+                        min={-90.0}
+                        max={90.0}
+                        step={45.0}
+                        curves={phases} />
+                    {
+                        selections.iter().enumerate().map(|(index, selection)| html! {
+                            <p class="legend">
+                                <span style={format!("color: {}", PLOT_COLOURS[index % PLOT_COLOURS.len()])}>{"■ "}</span>
+                                {format!(
+                                    "{}hz q {}: r1 {}, r2 {}, c1 {}, c2 {}",
+                                    format_units(selection.frequency()),
+                                    format_units(selection.q_factor()),
+                                    format_units(selection.r1_resistance()),
+                                    format_units(selection.r2_resistance()),
+                                    format_units(selection.c1_capacitance()),
+                                    format_units(selection.c2_capacitance())
+                                )}
+                            </p>
+                        }).collect::<Html>()
+                    }
+                </div>
+            }
+        }
+        Err(error) => html! {<p class="error">{error.to_string()}</p>},
+    }
+}
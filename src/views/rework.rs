@@ -0,0 +1,72 @@
+use crate::*;
+
+#[derive(Properties, PartialEq)]
+pub struct ReworkResultsProps {
+    pub results: UseStateHandle<Option<Result<Vec<Rework>, CalcError>>>,
+}
+
+#[function_component(ReworkResults)]
+pub fn rework_results(ReworkResultsProps { results }: &ReworkResultsProps) -> Html {
+    let format_part = |found: &Rework, name: &str, value: f64| {
+        if found.changes().iter().any(|change| change.name() == name) {
+            html! {<strong>{format_units(value)}</strong>}
+        } else {
+            html! {format_units(value)}
+        }
+    };
+
+    let Some(Ok(results)) = &**results else {
+        return html! {<Outcome<Vec<Rework>> results={results.clone()} />};
+    };
+
+    if results.is_empty() {
+        html! {<p>{format!("no results found changing up to {} parts", MAX_CHANGES)}</p>}
+    } else {
+        html! {
+            <>
+            <h2>{format!("{} results", results.len())}</h2>
+            <table>
+                <thead>
+                    <tr>
+                        <th class="changes">{"changes"}</th>
+                        <th class="frequency">{"frequency"}</th>
+                        <th class="q-factor">{"q factor"}</th>
+                        <th class="r1-resistance">{"r1"}</th>
+                        <th class="r2-resistance">{"r2"}</th>
+                        <th class="c1-capacitance">{"c1"}</th>
+                        <th class="c2-capacitance">{"c2"}</th>
+                    </tr>
+                </thead>
+                <tbody>
+                    {
+                        results.iter().map(|found| {
+                            let result = found.selection();
+                            let changes = found
+                                .changes()
+                                .iter()
+                                .map(|change| format!(
+                                    "{} {} → {}",
+                                    change.name(),
+                                    format_units(change.from()),
+                                    format_units(change.to())
+                                ))
+                                .collect::<Vec<_>>()
+                                .join(", ");
+
+                            html!{<tr>
+                                <td class="changes">{changes}</td>
+                                <td class="frequency">{format_units(result.frequency())}</td>
+                                <td class="q-factor">{format_units(result.q_factor())}</td>
+                                <td class="r1-resistance">{format_part(found, "r1", result.r1_resistance())}</td>
+                                <td class="r2-resistance">{format_part(found, "r2", result.r2_resistance())}</td>
+                                <td class="c1-capacitance">{format_part(found, "c1", result.c1_capacitance())}</td>
+                                <td class="c2-capacitance">{format_part(found, "c2", result.c2_capacitance())}</td>
+                            </tr>}
+                        }).collect::<Html>()
+                    }
+                </tbody>
+            </table>
+            </>
+        }
+    }
+}
@@ -0,0 +1,139 @@
+use crate::*;
+
+pub const CHUNKS_PER_WORKER: usize = 4;
+
+pub struct Search {
+    id: usize,
+    args: [Arg; 6],
+    bridges: Vec<WorkerBridge<SearchWorker>>,
+    dispatch: Dispatch,
+}
+
+pub fn worker_count() -> usize {
+    web_sys::window()
+        .map(|window| window.navigator().hardware_concurrency() as usize)
+        .unwrap_or(1)
+        .max(1)
+}
+
+pub fn stop_search(search: &RefCell<Option<Search>>) {
+    let stopped = search.borrow_mut().take();
+
+    if let Some(Search { bridges, .. }) = stopped {
+        drop(bridges);
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn start_search(
+    search: Rc<RefCell<Option<Search>>>,
+    id: usize,
+    args: [Arg; 6],
+    minimum: Option<usize>,
+    results: UseStateHandle<Option<Result<Vec<Selection>, CalcError>>>,
+    diagnostics: UseStateHandle<Option<Diagnostics>>,
+    relaxations: UseStateHandle<Vec<Relaxation>>,
+    progress: UseStateHandle<Option<(usize, usize)>>,
+) {
+    stop_search(&search);
+
+    let workers = if minimum.is_some() { 1 } else { worker_count() };
+    let mut dispatch = Dispatch::new(if minimum.is_some() {
+        1
+    } else {
+        workers * CHUNKS_PER_WORKER
+    });
+    let started = dispatch.start(workers);
+    let bridges = started
+        .iter()
+        .enumerate()
+        .map(|(index, _)| {
+            let search = search.clone();
+            let results = results.clone();
+            let diagnostics = diagnostics.clone();
+            let relaxations = relaxations.clone();
+            let progress = progress.clone();
+
+            SearchWorker::spawner()
+                .callback(move |done: ChunkDone| {
+                    let mut current = search.borrow_mut();
+                    let Some(active) = current.as_mut().filter(|active| active.id == id) else {
+                        return;
+                    };
+
+                    let found_relaxations = done.relaxations().to_vec();
+                    let step = done
+                        .into_result()
+                        .map(|found| active.dispatch.complete(found));
+
+                    match step {
+                        Ok(SendChunk(chunk)) => {
+                            progress.set(Some(active.dispatch.progress()));
+                            active.bridges[index].send(ChunkJob::new(
+                                chunk,
+                                active.dispatch.progress().1,
+                                active.args.clone(),
+                            ));
+                        }
+                        Ok(AwaitChunks) => {
+                            progress.set(Some(active.dispatch.progress()));
+                        }
+                        Ok(MergedChunks(found, found_diagnostics)) => {
+                            drop(current);
+                            stop_search(&search);
+                            results.set(Some(Ok(found)));
+                            diagnostics.set(Some(found_diagnostics));
+                            relaxations.set(found_relaxations);
+                            progress.set(None);
+                        }
+                        Err(error) => {
+                            drop(current);
+                            stop_search(&search);
+                            results.set(Some(Err(error)));
+                            diagnostics.set(None);
+                            progress.set(None);
+                        }
+                    }
+                })
+                .spawn("worker.js")
+        })
+        .collect::<Vec<_>>();
+
+    for (chunk, bridge) in started.into_iter().zip(bridges.iter()) {
+        bridge.send(match minimum {
+            Some(minimum) => ChunkJob::relaxed(args.clone(), minimum),
+            None => ChunkJob::new(chunk, dispatch.progress().1, args.clone()),
+        });
+    }
+
+    results.set(None);
+    diagnostics.set(None);
+    relaxations.set(Vec::new());
+    progress.set(Some(dispatch.progress()));
+    *search.borrow_mut() = Some(Search {
+        id,
+        args,
+        bridges,
+        dispatch,
+    });
+}
+
+#[derive(Properties, PartialEq)]
+pub struct ProgressProps {
+    pub progress: UseStateHandle<Option<(usize, usize)>>,
+    pub oncancel: Callback<MouseEvent>,
+}
+
+#[function_component(SearchProgress)]
+pub fn search_progress(ProgressProps { progress, oncancel }: &ProgressProps) -> Html {
+    match **progress {
+        Some((done, total)) => html! {
+            <div class="progress">
+                <progress value={done.to_string()} max={total.to_string()} />
+                <span>{format!("{} of {} chunks searched", done, total)}</span>
+                <button type="button" onclick={oncancel}>{"cancel"}</button>
+            </div>
+        },
+        None => html! {},
+    }
+}
@@ -0,0 +1,85 @@
+use crate::*;
+
+#[derive(Properties, PartialEq)]
+pub struct SensitivityProps {
+    pub selection: Selection,
+    pub topology: UseStateHandle<Topology>,
+}
+
+#[function_component(SensitivityView)]
+pub fn sensitivity_view(
+    SensitivityProps {
+        selection,
+        topology,
+    }: &SensitivityProps,
+) -> Html {
+    let found = sensitivities(selection, topology);
+    let rows = [
+        ("r1", found.r1()),
+        ("r2", found.r2()),
+        ("c1", found.c1()),
+        ("c2", found.c2()),
+    ];
+    let q_factor = topology.q_factor(
+        selection.r1_resistance(),
+        selection.r2_resistance(),
+        selection.c1_capacitance(),
+        selection.c2_capacitance(),
+    );
+
+    let on_topology_change = {
+        let topology = topology.clone();
+
+        Callback::from(move |event: Event| {
+            let element: EventTarget = event
+                .target()
+                .expect("Event should have a target when dispatched");
+            let input = element.unchecked_into::<HtmlInputElement>();
+
+            topology.set(Topology::from(input.value()));
+        })
+    };
+
+    html! {
+        <>
+        <h2>{"sensitivity"}</h2>
+        <div class="fieldset">
+            <div class="field">
+                <label for="topology">{"topology"}</label>
+                <select id="topology" onchange={on_topology_change}>
+                    {
+                        TOPOLOGY_OPTIONS.iter().map(|item_topology| html! {
+                            <option selected={**topology == *item_topology}>{item_topology.as_str()}</option>
+                        }).collect::<Html>()
+                    }
+                </select>
+            </div>
+        </div>
+        <p>{format!(
+            "q factor {} with this topology, total sensitivity {:.2}",
+            format_units(q_factor),
+            found.total()
+        )}</p>
+        <table>
+            <thead>
+                <tr>
+                    <th class="part">{"part"}</th>
+                    <th class="sensitivity">{"frequency"}</th>
+                    <th class="sensitivity">{"q factor"}</th>
+                </tr>
+            </thead>
+            <tbody>
+                {
+                    rows.iter().map(|(part, sensitivity)| {
+                        html!{<tr>
+                            <td class="part">{*part}</td>
+                            <td class="sensitivity">{format!("{:+.2}", sensitivity.frequency())}</td>
+                            <td class="sensitivity">{format!("{:+.2}", sensitivity.q_factor())}</td>
+                        </tr>}
+                    }).collect::<Html>()
+                }
+            </tbody>
+        </table>
+        </>
+    }
+}
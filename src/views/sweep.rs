@@ -0,0 +1,315 @@
+use crate::*;
+
+#[derive(Properties, PartialEq)]
+pub struct BehaviourProps {
+    pub value: UseStateHandle<QBehaviour>,
+    pub taper: UseStateHandle<Taper>,
+    pub points: UseStateHandle<String>,
+}
+
+#[function_component(BehaviourField)]
+pub fn behaviour_field(
+    BehaviourProps {
+        value,
+        taper,
+        points,
+    }: &BehaviourProps,
+) -> Html {
+    let on_behaviour_change = {
+        let state = value.clone();
+
+        move |event: Event| {
+            let element: EventTarget = event
+                .target()
+                .expect("Event should have a target when dispatched");
+
+            state.set(QBehaviour::from(
+                element.unchecked_into::<HtmlInputElement>().value(),
+            ));
+        }
+    };
+
+    let on_taper_change = {
+        let state = taper.clone();
+
+        move |event: Event| {
+            let element: EventTarget = event
+                .target()
+                .expect("Event should have a target when dispatched");
+
+            state.set(Taper::from(
+                element.unchecked_into::<HtmlInputElement>().value(),
+            ));
+        }
+    };
+
+    let on_points_change = {
+        let state = points.clone();
+
+        move |event: Event| {
+            let element: EventTarget = event
+                .target()
+                .expect("Event should have a target when dispatched");
+
+            state.set(element.unchecked_into::<HtmlInputElement>().value());
+        }
+    };
+
+    html! {
+        <>
+            <div class="field">
+                <label for="behaviour">{"q behaviour"}</label>
+                <select id="behaviour" onchange={on_behaviour_change}>
+                    {
+                        BEHAVIOUR_OPTIONS.iter().map(|item_behaviour| html! {
+                            <option selected={**value == *item_behaviour}>{item_behaviour.as_str()}</option>
+                        }).collect::<Html>()
+                    }
+                </select>
+                <p>{"the pot is added in series with the resistors in brackets"}</p>
+            </div>
+            <div class="field">
+                <label for="taper">{"pot taper"}</label>
+                <select id="taper" onchange={on_taper_change}>
+                    {
+                        TAPER_OPTIONS.iter().map(|item_taper| html! {
+                            <option selected={taper.as_str() == item_taper.as_str()}>{item_taper.as_str()}</option>
+                        }).collect::<Html>()
+                    }
+                </select>
+                {
+                    if matches!(**taper, Piecewise(_)) {
+                        html! {
+                            <>
+                            <input
+                                id="taper-points"
+                                placeholder="25:5, 50:15"
+                                value={(**points).clone()}
+                                onchange={on_points_change}
+                            />
+                            <p>{"rotation:resistance percentages between the ends of the track"}</p>
+                            </>
+                        }
+                    } else {
+                        html! {}
+                    }
+                }
+            </div>
+        </>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct RotationProps {
+    pub sweeps: Vec<Sweep>,
+}
+
+#[function_component(RotationPlot)]
+pub fn rotation_plot(RotationProps { sweeps }: &RotationProps) -> Html {
+    let curves = sweeps
+        .iter()
+        .map(|sweep| {
+            sweep
+                .points(PLOT_STEPS)
+                .iter()
+                .map(|(rotation, point)| (*rotation, point.frequency()))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let lowest = curves
+        .iter()
+        .flatten()
+        .map(|(_, frequency)| *frequency)
+        .fold(f64::INFINITY, f64::min);
+    let highest = curves
+        .iter()
+        .flatten()
+        .map(|(_, frequency)| *frequency)
+        .fold(f64::NEG_INFINITY, f64::max);
+    let start = 10f64.powf(lowest.log10().floor());
+    let stop = 10f64.powf(highest.log10().ceil());
+    let x = |rotation: f64| PLOT_MARGIN + rotation * PLOT_WIDTH;
+    let y = |frequency: f64| {
+        PLOT_HEIGHT + 10.0 - (frequency / start).ln() / (stop / start).ln() * PLOT_HEIGHT
+    };
+    let octaves = (0..=(stop / start).log2().floor() as i32)
+        .map(|octave| start * 2f64.powi(octave))
+        .collect::<Vec<_>>();
+
+    html! {
+        <div class="bode">
+            <svg
+                class="plot"
+                viewBox={format!("0 0 {} {}", PLOT_WIDTH + PLOT_MARGIN + 10.0, PLOT_HEIGHT + 40.0)}>
+                <text class="name" x={PLOT_MARGIN.to_string()} y="8">{"frequency against rotation"}</text>
+                {
+                    (0..=4).map(|quarter| {
+                        let rotation = quarter as f64 / 4.0;
+
+                        html! {
+                            <>
+                            <line
+                                class="grid"
+                                x1={x(rotation).to_string()}
+                                x2={x(rotation).to_string()}
+                                y1="10"
+                                y2={(PLOT_HEIGHT + 10.0).to_string()} />
+                            <text
+                                class="label"
+                                x={x(rotation).to_string()}
+                                y={(PLOT_HEIGHT + 25.0).to_string()}>
+                                {format!("{}%", quarter * 25)}
+                            </text>
+                            </>
+                        }
+                    }).collect::<Html>()
+                }
+                {
+                    octaves.iter().map(|octave| html! {
+                        <>
+                        <line
+                            class="grid"
+                            x1={PLOT_MARGIN.to_string()}
+                            x2={(PLOT_MARGIN + PLOT_WIDTH).to_string()}
+                            y1={y(*octave).to_string()}
+                            y2={y(*octave).to_string()} />
+                        <text class="label level" x={(PLOT_MARGIN - 5.0).to_string()} y={y(*octave).to_string()}>
+                            {format_units(*octave)}
+                        </text>
+                        </>
+                    }).collect::<Html>()
+                }
+                {
+                    curves.iter().enumerate().map(|(index, curve)| {
+                        let points = curve
+                            .iter()
+                            .map(|(rotation, frequency)| format!("{:.1},{:.1}", x(*rotation), y(*frequency)))
+                            .collect::<Vec<_>>()
+                            .join(" ");
+
+                        html! {
+                            <polyline
+                                class="curve"
+                                stroke={PLOT_COLOURS[index % PLOT_COLOURS.len()]}
+                                {points} />
+                        }
+                    }).collect::<Html>()
+                }
+            </svg>
+            {
+                sweeps.iter().enumerate().map(|(index, sweep)| html! {
+                    <p class="legend">
+                        <span style={format!("color: {}", PLOT_COLOURS[index % PLOT_COLOURS.len()])}>{"■ "}</span>
+                        {format!(
+                            "{} {} pot{}, {:.0}% from an even log sweep",
+                            format_units(sweep.pot()),
+                            sweep.taper().as_str(),
+                            match sweep.law_resistor() {
+                                Some(law_resistor) => format!(" with a {} law resistor", format_units(law_resistor)),
+                                None => "".into(),
+                            },
+                            sweep.unevenness() * 100.0
+                        )}
+                    </p>
+                }).collect::<Html>()
+            }
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct SweepResultsProps {
+    pub results: UseStateHandle<Option<Result<Vec<Sweep>, CalcError>>>,
+}
+
+#[function_component(SweepResults)]
+pub fn sweep_results(SweepResultsProps { results }: &SweepResultsProps) -> Html {
+    let Some(Ok(results)) = &**results else {
+        return html! {<Outcome<Vec<Sweep>> results={results.clone()} />};
+    };
+
+    if let Some(design) = results.first() {
+        let selection = design.selection();
+        let format_swept = |value: f64, swept: bool| {
+            if swept {
+                format!("{} + pot", format_units(value))
+            } else {
+                format_units(value)
+            }
+        };
+        let (r1_swept, r2_swept) = match design.behaviour() {
+            ConstantQ => (true, true),
+            ConstantBandwidth => (true, false),
+            FallingQ => (false, true),
+        };
+        let suggestion = design.suggest_law_resistor();
+        let sweeps = [Some(design.clone()), suggestion.clone()]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+
+        html! {
+            <>
+            <h2>{format!("best of {} designs", results.len())}</h2>
+            <table>
+                <thead>
+                    <tr>
+                        <th class="pot">{"pot"}</th>
+                        <th class="r1-resistance">{"r1"}</th>
+                        <th class="r2-resistance">{"r2"}</th>
+                        <th class="c1-capacitance">{"c1"}</th>
+                        <th class="c2-capacitance">{"c2"}</th>
+                    </tr>
+                </thead>
+                <tbody>
+                    <tr>
+                        <td class="pot">{format_units(design.pot())}</td>
+                        <td class="r1-resistance">{format_swept(selection.r1_resistance(), r1_swept)}</td>
+                        <td class="r2-resistance">{format_swept(selection.r2_resistance(), r2_swept)}</td>
+                        <td class="c1-capacitance">{format_units(selection.c1_capacitance())}</td>
+                        <td class="c2-capacitance">{format_units(selection.c2_capacitance())}</td>
+                    </tr>
+                </tbody>
+            </table>
+
+            <h2>{"pot rotation"}</h2>
+            <RotationPlot sweeps={sweeps} />
+            {
+                match &suggestion {
+                    Some(suggestion) => html! {
+                        <p>{format!(
+                            "a {} pot with a {} law resistor across it makes the sweep more even",
+                            format_units(suggestion.pot()),
+                            format_units(suggestion.law_resistor().unwrap_or_default())
+                        )}</p>
+                    },
+                    None => html! {<p>{"no law resistor makes this sweep more even"}</p>},
+                }
+            }
+            <TrackingView sweep={design.clone()} />
+            <table>
+                <thead>
+                    <tr>
+                        <th class="rotation">{"rotation"}</th>
+                        <th class="frequency">{"frequency"}</th>
+                        <th class="q-factor">{"q factor"}</th>
+                        <th class="bandwidth">{"bandwidth"}</th>
+                    </tr>
+                </thead>
+                <tbody>
+                    {
+                        design.points(SWEEP_STEPS).iter().map(|(rotation, point)| html! {<tr>
+                            <td class="rotation">{format!("{:.0}%", rotation * 100.0)}</td>
+                            <td class="frequency">{format_units(point.frequency())}</td>
+                            <td class="q-factor">{format_units(point.q_factor())}</td>
+                            <td class="bandwidth">{format_units(point.bandwidth())}</td>
+                        </tr>}).collect::<Html>()
+                    }
+                </tbody>
+            </table>
+            </>
+        }
+    } else {
+        html! {<p>{"no pot and fixed parts cover this sweep, try a wider tolerance"}</p>}
+    }
+}
@@ -0,0 +1,36 @@
+use crate::*;
+
+#[derive(Properties, PartialEq)]
+pub struct TargetsProps {
+    pub value: UseStateHandle<TargetPair>,
+}
+
+#[function_component(TargetsField)]
+pub fn targets_field(TargetsProps { value }: &TargetsProps) -> Html {
+    let on_targets_change = {
+        let state = value.clone();
+
+        move |event: Event| {
+            let element: EventTarget = event
+                .target()
+                .expect("Event should have a target when dispatched");
+
+            state.set(TargetPair::from(
+                element.unchecked_into::<HtmlInputElement>().value(),
+            ));
+        }
+    };
+
+    html! {
+        <div class="field">
+            <label for="targets">{"targets"}</label>
+            <select id="targets" onchange={on_targets_change}>
+                {
+                    TARGET_OPTIONS.iter().map(|item_pair| html! {
+                        <option selected={**value == *item_pair}>{item_pair.as_str()}</option>
+                    }).collect::<Html>()
+                }
+            </select>
+        </div>
+    }
+}
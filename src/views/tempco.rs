@@ -0,0 +1,122 @@
+use crate::*;
+
+#[derive(Properties, PartialEq)]
+pub struct TemperatureProps {
+    pub id: &'static str,
+    pub name: &'static str,
+    pub value: UseStateHandle<f64>,
+}
+
+#[function_component(TemperatureField)]
+pub fn temperature_field(TemperatureProps { id, name, value }: &TemperatureProps) -> Html {
+    let on_change = {
+        let value = value.clone();
+
+        Callback::from(move |event: Event| {
+            let element: EventTarget = event
+                .target()
+                .expect("Event should have a target when dispatched");
+            let input = element.unchecked_into::<HtmlInputElement>();
+
+            if let Ok(temperature) = input.value().parse::<f64>() {
+                value.set(temperature);
+            }
+        })
+    };
+
+    html! {
+        <div class="field">
+            <label for={*id}>{name}</label>
+            <select id={*id} onchange={on_change}>
+                {
+                    TEMPERATURE_OPTIONS.iter().map(|item_temperature| html! {
+                        <option
+                            selected={**value == *item_temperature}
+                            value={item_temperature.to_string()}>
+                            {format!("{}°c", item_temperature)}
+                        </option>
+                    }).collect::<Html>()
+                }
+            </select>
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct TempcoProps {
+    pub selection: Selection,
+}
+
+#[function_component(TempcoView)]
+pub fn tempco_view(TempcoProps { selection }: &TempcoProps) -> Html {
+    let defaults = Tempcos::default();
+    let r1_kind = use_state(|| defaults.r1().clone());
+    let r2_kind = use_state(|| defaults.r2().clone());
+    let c1_kind = use_state(|| defaults.c1().clone());
+    let c2_kind = use_state(|| defaults.c2().clone());
+    let lowest = use_state(|| 0.0);
+    let highest = use_state(|| 55.0);
+    let tempcos = Tempcos::new(
+        (*r1_kind).clone(),
+        (*r2_kind).clone(),
+        (*c1_kind).clone(),
+        (*c2_kind).clone(),
+    );
+
+    let found = match drift(selection, &tempcos, *lowest, *highest, TEMPERATURE_STEPS) {
+        Ok(found) => {
+            let (frequency_min, frequency_max) = found.frequency_range();
+            let (q_factor_min, q_factor_max) = found.q_factor_range();
+
+            html! {
+                <>
+                <p>{format!(
+                    "frequency drifts {:+.2}% to {:+.2}%, q factor {:+.2}% to {:+.2}%",
+                    frequency_min * 100.0,
+                    frequency_max * 100.0,
+                    q_factor_min * 100.0,
+                    q_factor_max * 100.0
+                )}</p>
+                <table>
+                    <thead>
+                        <tr>
+                            <th class="temperature">{"temperature"}</th>
+                            <th class="frequency">{"frequency"}</th>
+                            <th class="error">{"drift"}</th>
+                            <th class="q-factor">{"q factor"}</th>
+                            <th class="error">{"drift"}</th>
+                        </tr>
+                    </thead>
+                    <tbody>
+                        {
+                            found.points().iter().map(|(temperature, point)| html! {<tr>
+                                <td class="temperature">{format!("{:.1}°c", temperature)}</td>
+                                <td class="frequency">{format_units(point.frequency())}</td>
+                                <td class="error">{format!("{:+.2}%", found.frequency_drift(point) * 100.0)}</td>
+                                <td class="q-factor">{format_units(point.q_factor())}</td>
+                                <td class="error">{format!("{:+.2}%", found.q_factor_drift(point) * 100.0)}</td>
+                            </tr>}).collect::<Html>()
+                        }
+                    </tbody>
+                </table>
+                </>
+            }
+        }
+        Err(error) => html! {<p class="error">{error.to_string()}</p>},
+    };
+
+    html! {
+        <>
+        <h2>{format!("temperature drift from {}°c", REFERENCE_TEMPERATURE)}</h2>
+        <div class="fieldset">
+            <ResistorKindField id="r1-kind" name="r1 type" value={r1_kind} />
+            <ResistorKindField id="r2-kind" name="r2 type" value={r2_kind} />
+            <CapacitorKindField id="c1-kind" name="c1 type" value={c1_kind} />
+            <CapacitorKindField id="c2-kind" name="c2 type" value={c2_kind} />
+            <TemperatureField id="lowest-temperature" name="lowest temperature" value={lowest} />
+            <TemperatureField id="highest-temperature" name="highest temperature" value={highest} />
+        </div>
+        {found}
+        </>
+    }
+}
@@ -0,0 +1,50 @@
+use crate::*;
+
+#[derive(Properties, PartialEq)]
+pub struct ToleranceProps {
+    pub id: &'static str,
+    pub name: &'static str,
+    pub value: UseStateHandle<f64>,
+    #[prop_or(&COMPONENT_TOLERANCE_OPTIONS)]
+    pub options: &'static [f64],
+}
+
+#[function_component(ToleranceField)]
+pub fn tolerance_field(
+    ToleranceProps {
+        id,
+        name,
+        value,
+        options,
+    }: &ToleranceProps,
+) -> Html {
+    let on_change = {
+        let value = value.clone();
+
+        Callback::from(move |event: Event| {
+            let element: EventTarget = event
+                .target()
+                .expect("Event should have a target when dispatched");
+            let input = element.unchecked_into::<HtmlInputElement>();
+
+            value.set(parse_units(&input.value()));
+        })
+    };
+
+    html! {
+        <div class="field">
+            <label for={*id}>{name}</label>
+            <select id={*id} onchange={on_change}>
+                {
+                    options.iter().map(|item_tolerance| html! {
+                        <option
+                            selected={**value == *item_tolerance}
+                            value={format_units(*item_tolerance)}>
+                            {format!("±{}%", format_units(item_tolerance * 100.0))}
+                        </option>
+                    }).collect::<Html>()
+                }
+            </select>
+        </div>
+    }
+}
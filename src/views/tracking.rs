@@ -0,0 +1,67 @@
+use crate::*;
+
+#[derive(Properties, PartialEq)]
+pub struct TrackingProps {
+    pub sweep: Sweep,
+}
+
+#[function_component(TrackingView)]
+pub fn tracking_view(TrackingProps { sweep }: &TrackingProps) -> Html {
+    let error = use_state(|| 0.1);
+
+    let found = match tracking(sweep, *error, SWEEP_STEPS) {
+        Ok(found) => html! {
+            <>
+            {
+                match worst_mismatch(&found) {
+                    Some(worst) => html! {
+                        <p>{format!(
+                            "channels differ by up to {}% in frequency, at {:.0}% rotation",
+                            format_units(worst.frequency_mismatch().abs() * 100.0),
+                            worst.rotation() * 100.0
+                        )}</p>
+                    },
+                    None => html! {},
+                }
+            }
+            <table>
+                <thead>
+                    <tr>
+                        <th class="rotation">{"rotation"}</th>
+                        <th class="frequency">{"left"}</th>
+                        <th class="frequency">{"right"}</th>
+                        <th class="error">{"frequency mismatch"}</th>
+                        <th class="error">{"q mismatch"}</th>
+                    </tr>
+                </thead>
+                <tbody>
+                    {
+                        found.iter().map(|point| html! {<tr>
+                            <td class="rotation">{format!("{:.0}%", point.rotation() * 100.0)}</td>
+                            <td class="frequency">{format_units(point.left().frequency())}</td>
+                            <td class="frequency">{format_units(point.right().frequency())}</td>
+                            <td class="error">{format!("{:+.1}%", point.frequency_mismatch() * 100.0)}</td>
+                            <td class="error">{format!("{:+.1}%", point.q_factor_mismatch() * 100.0)}</td>
+                        </tr>}).collect::<Html>()
+                    }
+                </tbody>
+            </table>
+            </>
+        },
+        Err(error) => html! {<p class="error">{error.to_string()}</p>},
+    };
+
+    html! {
+        <>
+        <h2>{"stereo tracking"}</h2>
+        <div class="fieldset">
+            <ToleranceField
+                id="tracking-error"
+                name="gang tracking error"
+                options={&TRACKING_OPTIONS[..]}
+                value={error} />
+        </div>
+        {found}
+        </>
+    }
+}
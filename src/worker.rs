@@ -0,0 +1,212 @@
+use crate::diagnostics::*;
+use crate::relax::*;
+use crate::*;
+use gloo_worker::{HandlerId, Worker, WorkerScope};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ChunkJob(usize, usize, [Arg; 6], Option<usize>);
+
+impl ChunkJob {
+    pub fn new(chunk: usize, chunks: usize, args: [Arg; 6]) -> ChunkJob {
+        ChunkJob(chunk, chunks, args, None)
+    }
+
+    pub fn relaxed(args: [Arg; 6], minimum: usize) -> ChunkJob {
+        ChunkJob(0, 1, args, Some(minimum))
+    }
+
+    pub fn chunk(&self) -> usize {
+        self.0
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ChunkDone(
+    usize,
+    Result<(Vec<Selection>, Diagnostics), String>,
+    Vec<Relaxation>,
+);
+
+impl ChunkDone {
+    pub fn chunk(&self) -> usize {
+        self.0
+    }
+
+    pub fn relaxations(&self) -> &[Relaxation] {
+        &self.2
+    }
+
+    pub fn into_result(self) -> Result<(Vec<Selection>, Diagnostics), CalcError> {
+        self.1.map_err(CalcError::Worker)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum DispatchStep {
+    SendChunk(usize),
+    AwaitChunks,
+    MergedChunks(Vec<Selection>, Diagnostics),
+}
+
+pub use DispatchStep::*;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Dispatch {
+    next: usize,
+    chunks: usize,
+    found: Vec<(Vec<Selection>, Diagnostics)>,
+}
+
+impl Dispatch {
+    pub fn new(chunks: usize) -> Dispatch {
+        Dispatch {
+            next: 0,
+            chunks: chunks.max(1),
+            found: Vec::new(),
+        }
+    }
+
+    pub fn start(&mut self, workers: usize) -> Vec<usize> {
+        let started = (self.next..self.chunks.min(self.next + workers)).collect::<Vec<_>>();
+
+        self.next += started.len();
+        started
+    }
+
+    pub fn complete(&mut self, found: (Vec<Selection>, Diagnostics)) -> DispatchStep {
+        self.found.push(found);
+
+        if self.found.len() == self.chunks {
+            let (found, diagnostics) = merge_chunks(std::mem::take(&mut self.found));
+
+            MergedChunks(found, diagnostics)
+        } else if self.next < self.chunks {
+            self.next += 1;
+            SendChunk(self.next - 1)
+        } else {
+            AwaitChunks
+        }
+    }
+
+    pub fn progress(&self) -> (usize, usize) {
+        (self.found.len(), self.chunks)
+    }
+}
+
+pub struct SearchWorker;
+
+impl Worker for SearchWorker {
+    type Message = ();
+    type Input = ChunkJob;
+    type Output = ChunkDone;
+
+    fn create(_scope: &WorkerScope<Self>) -> Self {
+        SearchWorker
+    }
+
+    fn update(&mut self, _scope: &WorkerScope<Self>, _msg: Self::Message) {}
+
+    fn received(&mut self, scope: &WorkerScope<Self>, msg: Self::Input, id: HandlerId) {
+        let ChunkJob(chunk, chunks, [frequency, q_factor, r1, r2, c1, c2], minimum) = msg;
+        let done = match minimum {
+            Some(minimum) => {
+                match calculate_relaxed(frequency, q_factor, r1, r2, c1, c2, minimum) {
+                    Ok(relaxed) => ChunkDone(
+                        chunk,
+                        Ok((relaxed.selections().to_vec(), relaxed.diagnostics().clone())),
+                        relaxed.relaxations().to_vec(),
+                    ),
+                    Err(error) => ChunkDone(chunk, Err(error.to_string()), Vec::new()),
+                }
+            }
+            None => ChunkDone(
+                chunk,
+                calculate_chunk(frequency, q_factor, r1, r2, c1, c2, chunk, chunks)
+                    .map_err(|error| error.to_string()),
+                Vec::new(),
+            ),
+        };
+
+        scope.respond(id, done);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::series::*;
+
+    fn args() -> [Arg; 6] {
+        [
+            ArgWithTolerance(1000.0, 0.05),
+            ArgWithTolerance(2.0, 0.1),
+            ArgWithSeries(Series::E12, 100.0, 100000.0),
+            ArgWithSeries(Series::E12, 100.0, 100000.0),
+            ArgWithSeries(Series::E6, 1e-9, 1e-6),
+            ArgWithSeries(Series::E6, 1e-9, 1e-6),
+        ]
+    }
+
+    fn run(chunks: usize, workers: usize) -> (Vec<usize>, Vec<Selection>) {
+        let [frequency, q_factor, r1, r2, c1, c2] = args();
+        let mut dispatch = Dispatch::new(chunks);
+        let mut pending = dispatch.start(workers);
+        let mut sent = pending.clone();
+
+        while let Some(chunk) = pending.pop() {
+            let found = calculate_chunk(
+                frequency.clone(),
+                q_factor.clone(),
+                r1.clone(),
+                r2.clone(),
+                c1.clone(),
+                c2.clone(),
+                chunk,
+                chunks,
+            )
+            .unwrap();
+
+            match dispatch.complete(found) {
+                SendChunk(next) => {
+                    sent.push(next);
+                    pending.insert(0, next);
+                }
+                AwaitChunks => {}
+                MergedChunks(found, _) => {
+                    assert!(pending.is_empty());
+                    return (sent, found);
+                }
+            }
+        }
+
+        panic!("search never merged its chunks");
+    }
+
+    #[test]
+    fn dispatches_every_chunk_once() {
+        let (mut sent, _) = run(8, 3);
+
+        sent.sort();
+        assert_eq!(sent, (0..8).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn starts_no_more_workers_than_chunks() {
+        let mut dispatch = Dispatch::new(2);
+
+        assert_eq!(dispatch.start(4), vec![0, 1]);
+        assert_eq!(dispatch.progress(), (0, 2));
+    }
+
+    #[test]
+    fn merged_chunks_match_sequential_search() {
+        let [frequency, q_factor, r1, r2, c1, c2] = args();
+        let expected = calculate(frequency, q_factor, r1, r2, c1, c2).unwrap();
+
+        for (chunks, workers) in [(1, 1), (4, 2), (8, 3)] {
+            let (_, found) = run(chunks, workers);
+
+            assert_eq!(found.len(), expected.len());
+        }
+    }
+}